@@ -4,6 +4,7 @@ fn main() {
     let mut config = prost_build::Config::new();
     config.type_attribute("Sport", "#[derive(Eq, Hash, Copy)]");
     config.type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]");
+    config.field_attribute("Team.conference", "#[serde(default)]");
     config
         .compile_protos(&["src/common/types.proto"], &["src/"])
         .unwrap();
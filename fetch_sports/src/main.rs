@@ -1,24 +1,46 @@
 use lambda_runtime::{service_fn, LambdaEvent};
 use serde::Deserialize;
+use serde_json::Value;
 use std::collections::HashMap;
 use tracing::info;
 
-use live_sports::{Game, Sport};
+use live_sports::{FetchOptions, Game, Sport};
 
 use futures::future::join_all;
-use live_sports::fetch_sport;
 use live_sports::Error;
+use live_sports::{
+    fetch_box_score, fetch_headlines, fetch_plays, fetch_sport_with_options, fetch_standings,
+};
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 use lazy_static::lazy_static; // 1.4.0
 use std::str::FromStr;
 
-type Cache = HashMap<String, (Instant, Option<Vec<Game>>)>;
+type Cache = HashMap<(String, FetchOptions), (Instant, Option<Vec<Game>>)>;
 
 #[derive(Debug, Clone, Deserialize)]
 struct SportsRequest {
     sport_ids: Vec<String>,
+    #[serde(default)]
+    options: FetchOptions,
+}
+
+// API style requests such as {"path": "/v1/games/hockey/2023020001/plays"}. The field names
+// match API Gateway proxy events so the function can also sit behind one.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PathRequest {
+    path: String,
+    #[serde(default)]
+    query_string_parameters: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Request {
+    Sports(SportsRequest),
+    Path(PathRequest),
 }
 
 async fn get_sports(request: SportsRequest) -> Result<HashMap<String, Vec<Game>>, Error> {
@@ -30,14 +52,52 @@ async fn get_sports(request: SportsRequest) -> Result<HashMap<String, Vec<Game>>
         .map(|s| Sport::from_str(s))
         .collect();
     let sports = sports.map_err(|_| Error::InvalidSportType(format!("{:?}", request.sport_ids)))?;
-    get_scores_for_sports(&sports).await
+    get_scores_for_sports(&sports, &request.options).await
+}
+
+fn parse_sport(sport_id: &str) -> Result<Sport, Error> {
+    Sport::from_str(sport_id).map_err(|_| Error::InvalidSportType(sport_id.to_owned()))
+}
+
+async fn get_path(request: PathRequest) -> Result<Value, Error> {
+    tracing::info!("Getting path {}", request.path);
+    let query = request.query_string_parameters.unwrap_or_default();
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["v1", "games", sport_id, game_id, "plays"] => {
+            // Only scoring plays are returned unless ?all=true
+            let all_plays = query.get("all").is_some_and(|all| all == "true");
+            let plays = fetch_plays(parse_sport(sport_id)?, game_id.parse()?, !all_plays).await?;
+            Ok(serde_json::to_value(plays)?)
+        }
+        ["v1", "games", sport_id, game_id, "boxscore"] => {
+            let box_score = fetch_box_score(parse_sport(sport_id)?, game_id.parse()?).await?;
+            Ok(serde_json::to_value(box_score)?)
+        }
+        ["v1", "standings", sport_id] => {
+            let standings = fetch_standings(parse_sport(sport_id)?).await?;
+            Ok(serde_json::to_value(standings)?)
+        }
+        ["v1", "headlines", sport_id] => {
+            let max_length = match query.get("max_length") {
+                Some(max_length) => max_length.parse()?,
+                None => 80,
+            };
+            let headlines = fetch_headlines(parse_sport(sport_id)?, max_length).await?;
+            Ok(serde_json::to_value(headlines)?)
+        }
+        _ => Err(format!("No route for {}", request.path).into()),
+    }
 }
 
 lazy_static! {
     static ref CACHE: RwLock<Cache> = RwLock::new(Cache::new());
 }
 
-async fn get_scores_for_sports(sports: &[Sport]) -> Result<HashMap<String, Vec<Game>>, Error> {
+async fn get_scores_for_sports(
+    sports: &[Sport],
+    options: &FetchOptions,
+) -> Result<HashMap<String, Vec<Game>>, Error> {
     let mut results: HashMap<String, Vec<Game>> = HashMap::new();
     let mut futures = Vec::new();
 
@@ -46,7 +106,7 @@ async fn get_scores_for_sports(sports: &[Sport]) -> Result<HashMap<String, Vec<G
             .read()
             .map_err(|e| Error::InternalError(e.to_string()))?;
         for sport in sports {
-            if let Some((last_updated, result)) = cache.get(&sport.to_string()) {
+            if let Some((last_updated, result)) = cache.get(&(sport.to_string(), options.clone())) {
                 if Instant::now().duration_since(*last_updated) < Duration::from_secs(60) {
                     if let Some(result) = result {
                         results.insert(sport.to_string(), result.clone());
@@ -54,16 +114,16 @@ async fn get_scores_for_sports(sports: &[Sport]) -> Result<HashMap<String, Vec<G
                         return Err(Error::InternalError("Some weird error".to_string()));
                     }
                 } else {
-                    futures.push(fetch_sport(*sport));
+                    futures.push(fetch_sport_with_options(*sport, options));
                 }
             } else {
-                futures.push(fetch_sport(*sport));
+                futures.push(fetch_sport_with_options(*sport, options));
             }
         }
     }
 
     let mut maybe_err = None;
-    let new_results = join_all(futures.into_iter()).await;
+    let new_results = join_all(futures).await;
 
     let mut cache = CACHE
         .write()
@@ -71,11 +131,14 @@ async fn get_scores_for_sports(sports: &[Sport]) -> Result<HashMap<String, Vec<G
     for (sport, result) in new_results {
         match result {
             Ok(result) => {
-                cache.insert(sport.to_string(), (Instant::now(), Some(result.clone())));
+                cache.insert(
+                    (sport.to_string(), options.clone()),
+                    (Instant::now(), Some(result.clone())),
+                );
                 results.insert(sport.to_string(), result);
             }
             Err(e) => {
-                cache.insert(sport.to_string(), (Instant::now(), None));
+                cache.insert((sport.to_string(), options.clone()), (Instant::now(), None));
                 tracing::error!("Error when fetching sport {:?}: {:?}", sport, e);
                 maybe_err = Some(e);
             }
@@ -87,10 +150,13 @@ async fn get_scores_for_sports(sports: &[Sport]) -> Result<HashMap<String, Vec<G
     Ok(results)
 }
 
-async fn func(event: LambdaEvent<SportsRequest>) -> Result<HashMap<String, Vec<Game>>, Error> {
+async fn func(event: LambdaEvent<Request>) -> Result<Value, Error> {
     let (event, _context) = event.into_parts();
     info!("Calling function with event: {:?}", event);
-    get_sports(event).await
+    match event {
+        Request::Sports(request) => Ok(serde_json::to_value(get_sports(request).await?)?),
+        Request::Path(request) => get_path(request).await,
+    }
 }
 
 #[tokio::main]
@@ -0,0 +1,206 @@
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array, get_array_from_value, get_bool_from_value, get_f64_from_value, get_i64_from_value,
+    get_object, get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_from_value,
+    get_u64_str, get_u64_str_from_value,
+};
+use crate::common::team::get_last_name;
+use crate::common::types::box_score::stat_group::PlayerLine;
+use crate::common::types::box_score::{StatGroup, TeamBoxScore};
+use crate::common::types::BoxScore;
+
+fn get_strings(object: &Value, name: &'static str) -> Vec<String> {
+    get_array_from_value(object, name)
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_str)
+        .map(str::to_owned)
+        .collect()
+}
+
+fn new_player_line(
+    athlete: &Map<String, Value>,
+    is_starter: bool,
+    stats: Vec<String>,
+) -> PlayerLine {
+    let name = get_str(athlete, "displayName")
+        .or_else(|_| get_str(athlete, "fullName"))
+        .unwrap_or_default()
+        .to_uppercase();
+    PlayerLine {
+        display_name: get_last_name(&name),
+        name,
+        position: get_object(athlete, "position")
+            .and_then(|p| get_str(p, "abbreviation"))
+            .unwrap_or_default()
+            .to_owned(),
+        is_starter,
+        stats,
+    }
+}
+
+fn from_espn_group(group: &Value) -> StatGroup {
+    let players = get_array_from_value(group, "athletes")
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|player| {
+            let stats = get_strings(player, "stats");
+            // Players who didn't get in come through with no stats
+            if stats.is_empty() {
+                return None;
+            }
+            let athlete = get_object_from_value(player, "athlete").ok()?;
+            let is_starter = get_bool_from_value(player, "starter").unwrap_or(false);
+            Some(new_player_line(athlete, is_starter, stats))
+        })
+        .collect();
+    StatGroup {
+        name: get_str_from_value(group, "name")
+            .or_else(|_| get_str_from_value(group, "type"))
+            .unwrap_or_default()
+            .to_owned(),
+        labels: get_strings(group, "labels"),
+        players,
+        totals: get_strings(group, "totals"),
+    }
+}
+
+pub fn from_espn_summary(summary: &Map<String, Value>, game_id: u64) -> Result<BoxScore, Error> {
+    let competition = get_array(get_object(summary, "header")?, "competitions")?
+        .first()
+        .ok_or("Missing competitions in summary header")?;
+    let home = get_array_from_value(competition, "competitors")?
+        .iter()
+        .find(|c| get_str_from_value(c, "homeAway").is_ok_and(|side| side == "home"))
+        .ok_or("No home team in summary")?;
+    let home_id = get_u64_str_from_value(home, "id")?;
+
+    let mut box_score = BoxScore {
+        game_id,
+        ..Default::default()
+    };
+    for team in get_array(get_object(summary, "boxscore")?, "players")? {
+        let team_id = get_u64_str(get_object_from_value(team, "team")?, "id")?;
+        let team_box_score = TeamBoxScore {
+            team_id,
+            groups: get_array_from_value(team, "statistics")?
+                .iter()
+                .map(from_espn_group)
+                .collect(),
+        };
+        if team_id == home_id {
+            box_score.home_team = Some(team_box_score);
+        } else {
+            box_score.away_team = Some(team_box_score);
+        }
+    }
+    Ok(box_score)
+}
+
+fn get_statsapi_player_lines(
+    team: &Value,
+    ids_key: &'static str,
+    stats_key: &'static str,
+    get_stats: fn(&Value) -> Vec<String>,
+) -> Vec<PlayerLine> {
+    let players = match get_object_from_value(team, "players") {
+        Ok(players) => players,
+        Err(_) => return vec![],
+    };
+    get_array_from_value(team, ids_key)
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|id| {
+            let player = players.get(&format!("ID{}", id.as_u64()?))?;
+            // Scratched players have no stats for the game
+            let stats = player.get("stats")?.get(stats_key)?;
+            let athlete = get_object_from_value(player, "person").ok()?;
+            let position = get_object_from_value(player, "position").ok()?;
+            let mut line = new_player_line(athlete, false, get_stats(stats));
+            line.position = get_str(position, "abbreviation")
+                .unwrap_or_default()
+                .to_owned();
+            Some(line)
+        })
+        .collect()
+}
+
+fn get_skater_stats(stats: &Value) -> Vec<String> {
+    let stat = |name| get_u64_from_value(stats, name).unwrap_or(0).to_string();
+    vec![
+        stat("goals"),
+        stat("assists"),
+        stat("shots"),
+        get_i64_from_value(stats, "plusMinus")
+            .unwrap_or(0)
+            .to_string(),
+        get_str_from_value(stats, "timeOnIce")
+            .unwrap_or_default()
+            .to_owned(),
+    ]
+}
+
+fn get_goalie_stats(stats: &Value) -> Vec<String> {
+    let shots = get_u64_from_value(stats, "shots").unwrap_or(0);
+    let saves = get_u64_from_value(stats, "saves").unwrap_or(0);
+    vec![
+        shots.to_string(),
+        saves.to_string(),
+        shots.saturating_sub(saves).to_string(),
+        get_f64_from_value(stats, "savePercentage")
+            .map(|pct| format!("{:.3}", pct / 100.0))
+            .unwrap_or_default(),
+        get_str_from_value(stats, "timeOnIce")
+            .unwrap_or_default()
+            .to_owned(),
+    ]
+}
+
+fn from_statsapi_team(team: &Value) -> Result<TeamBoxScore, Error> {
+    let skaters = get_statsapi_player_lines(team, "skaters", "skaterStats", get_skater_stats);
+    let goalies = get_statsapi_player_lines(team, "goalies", "goalieStats", get_goalie_stats);
+
+    // Goals, assists and shots add up, ice time and +/- don't
+    let total = |index: usize| -> String {
+        skaters
+            .iter()
+            .filter_map(|line| line.stats.get(index)?.parse::<u64>().ok())
+            .sum::<u64>()
+            .to_string()
+    };
+    let skater_totals = vec![total(0), total(1), total(2), String::new(), String::new()];
+
+    Ok(TeamBoxScore {
+        team_id: get_u64(get_object_from_value(team, "team")?, "id")?,
+        groups: vec![
+            StatGroup {
+                name: "skaters".to_owned(),
+                labels: ["G", "A", "SOG", "+/-", "TOI"].map(str::to_owned).to_vec(),
+                players: skaters,
+                totals: skater_totals,
+            },
+            StatGroup {
+                name: "goalies".to_owned(),
+                labels: ["SA", "SV", "GA", "SV%", "TOI"].map(str::to_owned).to_vec(),
+                players: goalies,
+                totals: vec![],
+            },
+        ],
+    })
+}
+
+pub fn from_statsapi_box_score(json: &Map<String, Value>, game_id: u64) -> Result<BoxScore, Error> {
+    let teams = get_object(json, "teams")?;
+    let home = teams.get("home").ok_or("No home team present")?;
+    let away = teams.get("away").ok_or("No away team present")?;
+    Ok(BoxScore {
+        game_id,
+        home_team: Some(from_statsapi_team(home)?),
+        away_team: Some(from_statsapi_team(away)?),
+    })
+}
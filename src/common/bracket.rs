@@ -0,0 +1,301 @@
+use serde_json::{Map, Value};
+
+use crate::common::competition::{get_postseason, get_status_and_time};
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array, get_array_from_value, get_bool, get_bool_from_value, get_object,
+    get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_from_value, get_u64_str,
+    get_u64_str_from_value,
+};
+use crate::common::types::bracket::{Matchup, Round};
+use crate::common::types::game::Status;
+use crate::common::types::sport::{Level, SportType};
+use crate::common::types::{Bracket, Sport};
+
+// Seeds come through as numbers in some feeds and strings in others
+fn get_seed(competitor: &Value) -> u64 {
+    match competitor.get("seed") {
+        Some(Value::Number(seed)) => seed.as_u64().unwrap_or(0),
+        Some(Value::String(seed)) => seed.parse().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+// Splits "Men's Basketball Championship - South Region - 1st Round" or "East 1st Round"
+// into its region and round
+fn split_round_name(round_name: &str) -> (String, String) {
+    let mut region = String::new();
+    let mut round = "";
+    for part in round_name.split(" - ") {
+        if let Some(r) = part.strip_suffix(" Region") {
+            region = r.to_owned();
+        } else {
+            round = part;
+        }
+    }
+    for conference in ["East", "West"] {
+        if let Some(r) = round
+            .strip_prefix(conference)
+            .and_then(|r| r.strip_prefix(' '))
+        {
+            if region.is_empty() {
+                return (conference.to_owned(), r.to_owned());
+            }
+        }
+    }
+    (region, round.to_owned())
+}
+
+// Bowl games, the NIT and the like share the postseason with the tournaments we want
+fn is_bracket_game(sport: &Sport, headline: &str) -> bool {
+    match (sport.sport_type(), sport.level()) {
+        (SportType::Football, Level::Collegiate) => {
+            headline.contains("CFP") || headline.contains("College Football Playoff")
+        }
+        (SportType::Basketball, Level::Collegiate) => {
+            headline.contains("Men's Basketball Championship")
+        }
+        _ => true,
+    }
+}
+
+fn get_winner(competition: &Value, competitors: &[&Value]) -> Result<u64, Error> {
+    // A series is only decided once it's marked completed, whoever won tonight
+    if let Ok(series) = get_object_from_value(competition, "series") {
+        if !get_bool(series, "completed").unwrap_or(false) {
+            return Ok(0);
+        }
+        let leader = get_array(series, "competitors")?
+            .iter()
+            .max_by_key(|c| get_u64_from_value(c, "wins").unwrap_or(0));
+        return match leader {
+            Some(leader) => get_u64_str_from_value(leader, "id"),
+            None => Ok(0),
+        };
+    }
+    for competitor in competitors {
+        if get_bool_from_value(competitor, "winner").unwrap_or(false) {
+            return get_u64_str(get_object_from_value(competitor, "team")?, "id");
+        }
+    }
+    Ok(0)
+}
+
+pub fn from_espn_postseason(sport: &Sport, events: &[Value]) -> Result<Vec<Round>, Error> {
+    let mut events = events.iter().collect::<Vec<_>>();
+    events.sort_by_key(|event| get_str_from_value(event, "date").unwrap_or_default());
+
+    let mut rounds: Vec<Round> = vec![];
+    for event in events {
+        let competition = get_array_from_value(event, "competitions")?
+            .first()
+            .ok_or(format!("Missing competitions in {event}"))?;
+        let postseason = match get_postseason(event, competition) {
+            Some(postseason) if is_bracket_game(sport, &postseason.headline) => postseason,
+            _ => continue,
+        };
+        let status = match get_status_and_time(competition, None)? {
+            Some((status, _)) => status,
+            None => continue,
+        };
+
+        let competitors = get_array_from_value(competition, "competitors")?;
+        let find_side = |side| {
+            competitors
+                .iter()
+                .find(|c| get_str_from_value(c, "homeAway").is_ok_and(|s| s == side))
+                .ok_or(format!("No {side} team in {event}"))
+        };
+        let (home, away) = (find_side("home")?, find_side("away")?);
+        let home_id = get_u64_str(get_object_from_value(home, "team")?, "id")?;
+        let away_id = get_u64_str(get_object_from_value(away, "team")?, "id")?;
+
+        let (region, round_name) = split_round_name(&postseason.round_name);
+        let round = match rounds.iter().position(|r| r.name == round_name) {
+            Some(index) => &mut rounds[index],
+            None => {
+                rounds.push(Round {
+                    number: rounds.len() as u64 + 1,
+                    name: round_name,
+                    matchups: vec![],
+                });
+                rounds.last_mut().ok_or("No rounds")?
+            }
+        };
+
+        let same_teams = |m: &Matchup| {
+            (m.home_team_id, m.away_team_id) == (home_id, away_id)
+                || (m.home_team_id, m.away_team_id) == (away_id, home_id)
+        };
+        let matchup = match round.matchups.iter().position(same_teams) {
+            Some(index) => &mut round.matchups[index],
+            None => {
+                round.matchups.push(Matchup {
+                    region,
+                    home_team_id: home_id,
+                    away_team_id: away_id,
+                    home_seed: get_seed(home),
+                    away_seed: get_seed(away),
+                    ..Default::default()
+                });
+                round.matchups.last_mut().ok_or("No matchups")?
+            }
+        };
+        matchup
+            .game_ids
+            .push(get_u64_str_from_value(competition, "id")?);
+        matchup.summary = postseason.series_summary;
+        if status == Status::End {
+            matchup.winner_team_id = get_winner(competition, &[home, away])?;
+        }
+    }
+    Ok(rounds)
+}
+
+fn from_statsapi_series(series: &Value, wins_needed: Option<u64>) -> Option<Matchup> {
+    let teams = get_array_from_value(series, "matchupTeams").ok()?;
+    let (home, away) = (teams.first()?, teams.get(1)?);
+    let team_id = |team: &Value| {
+        get_object_from_value(team, "team")
+            .and_then(|t| get_u64(t, "id"))
+            .ok()
+    };
+    let seed = |team: &Value| {
+        get_object_from_value(team, "seed")
+            .and_then(|s| get_u64(s, "rank"))
+            .unwrap_or(0)
+    };
+    let wins = |team: &Value| {
+        get_object_from_value(team, "seriesRecord")
+            .and_then(|r| get_u64(r, "wins"))
+            .unwrap_or(0)
+    };
+    let summary = get_object_from_value(series, "currentGame")
+        .and_then(|game| get_object(game, "seriesSummary"))
+        .ok();
+    let (home_id, away_id) = (team_id(home)?, team_id(away)?);
+
+    let winner_team_id = match wins_needed {
+        Some(wins_needed) if wins(home) >= wins_needed => home_id,
+        Some(wins_needed) if wins(away) >= wins_needed => away_id,
+        _ => 0,
+    };
+    Some(Matchup {
+        region: get_object_from_value(series, "conference")
+            .and_then(|c| get_str(c, "name"))
+            .unwrap_or_default()
+            .to_owned(),
+        home_team_id: home_id,
+        away_team_id: away_id,
+        home_seed: seed(home),
+        away_seed: seed(away),
+        winner_team_id,
+        summary: summary
+            .and_then(|s| get_str(s, "seriesStatus").ok())
+            .unwrap_or_default()
+            .to_owned(),
+        game_ids: summary
+            .and_then(|s| get_u64(s, "gamePk").ok())
+            .into_iter()
+            .collect(),
+    })
+}
+
+pub fn from_statsapi_playoffs(json: &Map<String, Value>) -> Result<Vec<Round>, Error> {
+    get_array(json, "rounds")?
+        .iter()
+        .map(|round| {
+            // Series lengths vary by round and season, e.g. best of five in the 2020 qualifiers
+            let format = get_object_from_value(round, "format").ok();
+            let wins_needed = format
+                .and_then(|f| get_u64(f, "numberOfWins").ok())
+                .or_else(|| {
+                    format
+                        .and_then(|f| get_u64(f, "numberOfGames").ok())
+                        .map(|games| games / 2 + 1)
+                });
+            Ok(Round {
+                number: get_u64_from_value(round, "number")?,
+                name: get_object_from_value(round, "names")
+                    .and_then(|names| get_str(names, "name"))
+                    .unwrap_or_default()
+                    .to_owned(),
+                matchups: get_array_from_value(round, "series")
+                    .map(|s| s.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|series| from_statsapi_series(series, wins_needed))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+// The earliest round that still has a matchup to decide, or the final once it's over
+pub fn get_current_round(bracket: &Bracket) -> Option<&Round> {
+    bracket
+        .rounds
+        .iter()
+        .find(|round| round.matchups.iter().any(|m| m.winner_team_id == 0))
+        .or_else(|| bracket.rounds.last())
+}
+
+// Every matchup a team has played or is playing, in round order
+pub fn get_team_path(bracket: &Bracket, team_id: u64) -> Vec<Matchup> {
+    bracket
+        .rounds
+        .iter()
+        .flat_map(|round| &round.matchups)
+        .filter(|m| m.home_team_id == team_id || m.away_team_id == team_id)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_statsapi_series_winner() {
+        let team = |id, wins| json!({"team": {"id": id}, "seriesRecord": {"wins": wins}});
+        let json = json!({
+            "rounds": [
+                {
+                    "number": 1,
+                    "format": {"numberOfGames": 5, "numberOfWins": 3},
+                    "series": [{"matchupTeams": [team(6, 3), team(5, 1)]}],
+                },
+                {
+                    "number": 2,
+                    "format": {"numberOfGames": 7},
+                    "series": [{"matchupTeams": [team(6, 3), team(10, 2)]}],
+                },
+            ],
+        });
+        let rounds = from_statsapi_playoffs(json.as_object().unwrap()).unwrap();
+        assert_eq!(rounds[0].matchups[0].winner_team_id, 6);
+        assert_eq!(rounds[1].matchups[0].winner_team_id, 0);
+    }
+
+    #[test]
+    fn test_split_round_name() {
+        let split = |name| split_round_name(name);
+        assert_eq!(
+            split("Men's Basketball Championship - South Region - 1st Round"),
+            ("South".to_owned(), "1st Round".to_owned())
+        );
+        assert_eq!(
+            split("East 1st Round"),
+            ("East".to_owned(), "1st Round".to_owned())
+        );
+        assert_eq!(
+            split("Rose Bowl Game - CFP Semifinal"),
+            (String::new(), "CFP Semifinal".to_owned())
+        );
+        assert_eq!(
+            split("NBA Finals"),
+            (String::new(), "NBA Finals".to_owned())
+        );
+    }
+}
@@ -0,0 +1,378 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use ordinal::Ordinal;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array_from_value, get_bool_from_value, get_f64_from_value, get_i64_from_value, get_object,
+    get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_from_value, get_u64_str,
+    get_u64_str_from_value,
+};
+use crate::common::proto_helpers::from_espn;
+use crate::common::team::get_last_name;
+use crate::common::types::game::leader::Side;
+use crate::common::types::game::odds::Favorite;
+use crate::common::types::game::{
+    Broadcast, Leader, Odds, Postseason, Status, Venue, WinProbability,
+};
+
+// Status and start time of an ESPN competition. Postponed and canceled competitions are None,
+// as are any starting more than window_hours either side of now
+pub fn get_status_and_time(
+    competition: &Value,
+    window_hours: Option<i64>,
+) -> Result<Option<(Status, DateTime<Utc>)>, Error> {
+    let status_object = get_object_from_value(competition, "status")?;
+    let status = from_espn(get_str(get_object(status_object, "type")?, "name")?);
+    if status == Status::Invalid {
+        return Ok(None);
+    }
+
+    let time_str = get_str_from_value(competition, "date")?;
+    let time = NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%MZ")?;
+    let time: DateTime<Utc> = DateTime::from_utc(time, Utc);
+    if let Some(window_hours) = window_hours {
+        let delta_hours = Utc::now().signed_duration_since(time).num_hours().abs();
+        if delta_hours > window_hours {
+            return Ok(None);
+        }
+    }
+    Ok(Some((status, time)))
+}
+
+// "1st", "2nd" and so on through regulation, then "OT", "2OT" and so on
+pub fn get_overtime_ordinal(period: u64, regulation_periods: u64) -> String {
+    match period.saturating_sub(regulation_periods) {
+        0 => Ordinal(period).to_string(),
+        1 => "OT".to_owned(),
+        overtime => format!("{overtime}OT"),
+    }
+}
+
+pub fn get_broadcasts(competition: &Value) -> Vec<Broadcast> {
+    // geoBroadcasts says whether each is national and TV or streaming, older feeds only have names
+    if let Ok(geo_broadcasts) = get_array_from_value(competition, "geoBroadcasts") {
+        geo_broadcasts
+            .iter()
+            .filter_map(|broadcast| {
+                let media_type = get_object_from_value(broadcast, "type")
+                    .and_then(|t| get_str(t, "shortName"))
+                    .unwrap_or("TV");
+                if media_type == "Radio" {
+                    return None;
+                }
+                let network = get_object_from_value(broadcast, "media")
+                    .and_then(|media| get_str(media, "shortName"))
+                    .ok()?
+                    .to_owned();
+                let is_national = get_object_from_value(broadcast, "market")
+                    .and_then(|market| get_str(market, "type"))
+                    .is_ok_and(|market| market == "National");
+                Some(Broadcast {
+                    network,
+                    is_national,
+                    is_streaming: media_type == "Streaming",
+                })
+            })
+            .unique_by(|b| b.network.clone())
+            .collect()
+    } else {
+        get_array_from_value(competition, "broadcasts")
+            .map(|b| b.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|broadcast| {
+                let is_national =
+                    get_str_from_value(broadcast, "market").is_ok_and(|m| m == "national");
+                get_array_from_value(broadcast, "names")
+                    .map(|n| n.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(move |network| Broadcast {
+                        network: network.to_owned(),
+                        is_national,
+                        is_streaming: false,
+                    })
+            })
+            .collect()
+    }
+}
+
+pub fn get_venue(competition: &Value) -> Option<Venue> {
+    let venue = get_object_from_value(competition, "venue").ok()?;
+    let address = get_object(venue, "address").ok();
+    Some(Venue {
+        name: get_str(venue, "fullName").unwrap_or_default().to_owned(),
+        city: address
+            .and_then(|a| get_str(a, "city").ok())
+            .unwrap_or_default()
+            .to_owned(),
+        state: address
+            .and_then(|a| get_str(a, "state").ok())
+            .unwrap_or_default()
+            .to_owned(),
+    })
+}
+
+pub fn get_odds(competition: &Value) -> Option<Odds> {
+    // The first provider listed is the one ESPN shows on its own scoreboard
+    let odds = get_array_from_value(competition, "odds").ok()?.first()?;
+    let (home_odds, away_odds) = (odds.get("homeTeamOdds"), odds.get("awayTeamOdds"));
+    let is_favorite = |team_odds: Option<&Value>| {
+        team_odds
+            .and_then(|o| get_bool_from_value(o, "favorite").ok())
+            .unwrap_or(false)
+    };
+    let moneyline = |team_odds: Option<&Value>| {
+        team_odds
+            .and_then(|o| get_i64_from_value(o, "moneyLine").ok())
+            .unwrap_or(0)
+    };
+    let favorite = if is_favorite(home_odds) {
+        Favorite::Home
+    } else if is_favorite(away_odds) {
+        Favorite::Away
+    } else {
+        Favorite::None
+    };
+    Some(Odds {
+        provider: get_object_from_value(odds, "provider")
+            .and_then(|p| get_str(p, "name"))
+            .unwrap_or_default()
+            .to_owned(),
+        details: get_str_from_value(odds, "details")
+            .unwrap_or_default()
+            .to_owned(),
+        spread: get_f64_from_value(odds, "spread").unwrap_or(0.0),
+        over_under: get_f64_from_value(odds, "overUnder").unwrap_or(0.0),
+        home_moneyline: moneyline(home_odds),
+        away_moneyline: moneyline(away_odds),
+        favorite: favorite.into(),
+    })
+}
+
+pub fn get_win_probability(competition: &Value) -> Option<WinProbability> {
+    let last_play = get_object_from_value(competition, "situation")
+        .and_then(|situation| get_object(situation, "lastPlay"))
+        .ok()?;
+    let probability = last_play.get("probability")?;
+    Some(WinProbability {
+        home_win_percentage: get_f64_from_value(probability, "homeWinPercentage").ok()? * 100.0,
+        tie_percentage: get_f64_from_value(probability, "tiePercentage").unwrap_or(0.0) * 100.0,
+        last_play: get_str(last_play, "text").unwrap_or_default().to_owned(),
+        is_predictor: false,
+    })
+}
+
+pub fn get_predictor(summary: &Map<String, Value>) -> Option<WinProbability> {
+    let predictor = get_object(summary, "predictor").ok()?;
+    // Projections come through as strings like "62.3"
+    let projection = |team| -> Option<f64> {
+        get_object(predictor, team)
+            .and_then(|t| get_str(t, "gameProjection"))
+            .ok()?
+            .parse()
+            .ok()
+    };
+    let home = projection("homeTeam")?;
+    let away = projection("awayTeam").unwrap_or(100.0 - home);
+    Some(WinProbability {
+        home_win_percentage: home,
+        tie_percentage: (100.0 - home - away).max(0.0),
+        last_play: String::new(),
+        is_predictor: true,
+    })
+}
+
+pub fn get_postseason(event: &Value, competition: &Value) -> Option<Postseason> {
+    lazy_static! {
+        static ref GAME_NUMBER: Regex = Regex::new(r"^Game (\d+)$").unwrap();
+    }
+    let is_postseason = get_object_from_value(event, "season")
+        .and_then(|season| get_u64(season, "type"))
+        .is_ok_and(|season_type| season_type == 3);
+    let series = get_object_from_value(competition, "series").ok();
+    if !is_postseason && series.is_none() {
+        return None;
+    }
+
+    // Headlines look like "East 1st Round - Game 5" or "Rose Bowl Game - CFP Semifinal"
+    let headline = get_array_from_value(competition, "notes")
+        .ok()
+        .and_then(|notes| notes.first())
+        .and_then(|note| get_str_from_value(note, "headline").ok())
+        .unwrap_or_default();
+    let (game_number, round_parts): (Vec<_>, Vec<_>) = headline
+        .split(" - ")
+        .partition(|part| GAME_NUMBER.is_match(part));
+    let game_number = game_number
+        .first()
+        .and_then(|part| GAME_NUMBER.captures(part))
+        .and_then(|captures| captures[1].parse().ok())
+        .unwrap_or(0);
+    let round_name = match series.and_then(|s| get_str(s, "title").ok()) {
+        Some(title) => title.to_owned(),
+        None => round_parts.join(" - "),
+    };
+
+    Some(Postseason {
+        series_summary: series
+            .and_then(|s| get_str(s, "summary").ok())
+            .unwrap_or_default()
+            .to_owned(),
+        game_number,
+        round_name,
+        headline: headline.to_owned(),
+    })
+}
+
+fn get_nhl_round_name(round: u64) -> &'static str {
+    match round {
+        1 => "First Round",
+        2 => "Second Round",
+        3 => "Conference Final",
+        4 => "Stanley Cup Final",
+        _ => "",
+    }
+}
+
+pub fn get_statsapi_postseason(game: &Value) -> Option<Postseason> {
+    if get_str_from_value(game, "gameType").ok()? != "P" {
+        return None;
+    }
+    let summary = game.get("seriesSummary");
+    let summary_str = |name| {
+        summary
+            .and_then(|s| get_str_from_value(s, name).ok())
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let round = summary
+        .and_then(|s| s.get("series"))
+        .and_then(|series| get_object_from_value(series, "round").ok())
+        .and_then(|round| get_u64(round, "number").ok())
+        .unwrap_or(0);
+    Some(Postseason {
+        series_summary: summary_str("seriesStatus"),
+        game_number: summary
+            .and_then(|s| get_u64_from_value(s, "gameNumber").ok())
+            .unwrap_or(0),
+        round_name: get_nhl_round_name(round).to_owned(),
+        headline: summary_str("gameLabel"),
+    })
+}
+
+fn get_side(team_id: u64, home_id: u64, away_id: u64) -> Side {
+    match team_id {
+        0 => Side::None,
+        id if id == home_id => Side::Home,
+        id if id == away_id => Side::Away,
+        _ => Side::None,
+    }
+}
+
+// Leaders listed under a competitor may leave off their team, so they take that competitor's side
+fn get_category_leader(
+    category: &Value,
+    holder_side: Side,
+    home_id: u64,
+    away_id: u64,
+) -> Option<Leader> {
+    let leader = get_array_from_value(category, "leaders").ok()?.first()?;
+    let name = get_object_from_value(leader, "athlete")
+        .and_then(|athlete| get_str(athlete, "displayName"))
+        .ok()?
+        .to_uppercase();
+    let side = match get_object_from_value(leader, "team").and_then(|team| get_u64_str(team, "id"))
+    {
+        Ok(id) => get_side(id, home_id, away_id),
+        Err(_) => holder_side,
+    };
+    Some(Leader {
+        category: get_str_from_value(category, "shortDisplayName")
+            .or_else(|_| get_str_from_value(category, "abbreviation"))
+            .unwrap_or_default()
+            .to_uppercase(),
+        display_name: get_last_name(&name),
+        name,
+        stat_line: get_str_from_value(leader, "displayValue")
+            .unwrap_or_default()
+            .replace(',', "")
+            .to_uppercase(),
+        side: side.into(),
+    })
+}
+
+pub fn get_leaders(competition: &Value, home_id: u64, away_id: u64) -> Vec<Leader> {
+    // Football lists leaders for the whole game, other sports list them under each team
+    let mut holders = vec![(competition, Side::None)];
+    for competitor in get_array_from_value(competition, "competitors")
+        .map(|c| c.as_slice())
+        .unwrap_or_default()
+    {
+        let team_id = get_u64_str_from_value(competitor, "id").unwrap_or(0);
+        holders.push((competitor, get_side(team_id, home_id, away_id)));
+    }
+
+    let mut leaders = vec![];
+    for (holder, holder_side) in holders {
+        for category in get_array_from_value(holder, "leaders")
+            .map(|l| l.as_slice())
+            .unwrap_or_default()
+        {
+            leaders.extend(get_category_leader(category, holder_side, home_id, away_id));
+        }
+    }
+    leaders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_postseason_from_notes() {
+        let event = json!({"season": {"type": 3}});
+        let competition = json!({
+            "notes": [{"headline": "East 1st Round - Game 5"}],
+            "series": {"summary": "BOS leads series 3-1"},
+        });
+        let postseason = get_postseason(&event, &competition).unwrap();
+        assert_eq!(postseason.game_number, 5);
+        assert_eq!(postseason.round_name, "East 1st Round");
+        assert_eq!(postseason.series_summary, "BOS leads series 3-1");
+
+        let bowl = json!({"notes": [{"headline": "Rose Bowl Game - CFP Semifinal"}]});
+        let postseason = get_postseason(&event, &bowl).unwrap();
+        assert_eq!(postseason.game_number, 0);
+        assert_eq!(postseason.round_name, "Rose Bowl Game - CFP Semifinal");
+
+        let regular_season = json!({"season": {"type": 2}});
+        assert!(get_postseason(&regular_season, &bowl).is_none());
+    }
+
+    #[test]
+    fn test_leaders_without_team_take_competitor_side() {
+        let competition = json!({
+            "competitors": [{
+                "id": "2",
+                "leaders": [{
+                    "shortDisplayName": "PTS",
+                    "leaders": [{
+                        "displayValue": "31",
+                        "athlete": {"displayName": "Jayson Tatum"},
+                    }],
+                }],
+            }],
+        });
+        let leaders = get_leaders(&competition, 1, 2);
+        assert_eq!(leaders.len(), 1);
+        assert_eq!(leaders[0].side(), Side::Away);
+        assert_eq!(leaders[0].display_name, "TATUM");
+    }
+}
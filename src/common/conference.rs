@@ -0,0 +1,135 @@
+use crate::common::types::sport::SportType;
+
+pub struct Conference {
+    // Used in sport ids, e.g. "college-football:big-ten"
+    pub slug: &'static str,
+    pub name: &'static str,
+    // ESPN group ids, None when the conference doesn't play that sport in Division I
+    pub football_group: Option<u64>,
+    pub basketball_group: Option<u64>,
+}
+
+const fn conference(
+    slug: &'static str,
+    name: &'static str,
+    football_group: Option<u64>,
+    basketball_group: Option<u64>,
+) -> Conference {
+    Conference {
+        slug,
+        name,
+        football_group,
+        basketball_group,
+    }
+}
+
+// Divisions are listed alongside conferences so a request can widen as well as narrow
+pub const CONFERENCES: [Conference; 38] = [
+    conference("fbs", "FBS", Some(80), None),
+    conference("fcs", "FCS", Some(81), None),
+    conference("division-i", "Division I", None, Some(50)),
+    conference("acc", "ACC", Some(1), Some(2)),
+    conference("big-12", "Big 12", Some(4), Some(8)),
+    conference("big-ten", "Big Ten", Some(5), Some(7)),
+    conference("sec", "SEC", Some(8), Some(23)),
+    conference("pac-12", "Pac-12", Some(9), Some(21)),
+    conference("big-east", "Big East", None, Some(4)),
+    conference("american", "American", Some(151), Some(62)),
+    conference("mountain-west", "Mountain West", Some(17), Some(44)),
+    conference("sun-belt", "Sun Belt", Some(37), Some(27)),
+    conference("mac", "MAC", Some(15), Some(14)),
+    conference("c-usa", "Conference USA", Some(12), Some(11)),
+    conference("independent", "FBS Independents", Some(18), None),
+    conference("atlantic-10", "Atlantic 10", None, Some(3)),
+    conference("wcc", "West Coast", None, Some(29)),
+    conference("mvc", "Missouri Valley", None, Some(18)),
+    conference("ivy", "Ivy League", Some(22), Some(12)),
+    conference("big-sky", "Big Sky", Some(20), Some(5)),
+    conference("caa", "Coastal Athletic", Some(48), Some(10)),
+    conference("america-east", "America East", None, Some(1)),
+    conference("asun", "ASUN", None, Some(46)),
+    conference("big-south", "Big South", Some(40), Some(6)),
+    conference("big-west", "Big West", None, Some(9)),
+    conference("horizon", "Horizon League", None, Some(45)),
+    conference("maac", "MAAC", None, Some(13)),
+    conference("meac", "MEAC", Some(24), Some(16)),
+    conference("nec", "Northeast", Some(25), Some(19)),
+    conference("ovc", "Ohio Valley", Some(26), Some(20)),
+    conference("patriot", "Patriot League", Some(27), Some(22)),
+    conference("southern", "Southern", Some(29), Some(24)),
+    conference("southland", "Southland", Some(30), Some(25)),
+    conference("summit", "Summit League", None, Some(49)),
+    conference("swac", "SWAC", Some(31), Some(26)),
+    conference("wac", "WAC", None, Some(30)),
+    // FCS conferences that only play football
+    conference("mvfc", "Missouri Valley Football", Some(21), None),
+    conference("pioneer", "Pioneer League", Some(28), None),
+];
+
+fn get_group(conference: &Conference, sport_type: SportType) -> Option<u64> {
+    match sport_type {
+        SportType::Football => conference.football_group,
+        SportType::Basketball => conference.basketball_group,
+        _ => None,
+    }
+}
+
+pub fn get_group_id(sport_type: SportType, slug: &str) -> Option<u64> {
+    CONFERENCES
+        .iter()
+        .find(|c| c.slug == slug)
+        .and_then(|c| get_group(c, sport_type))
+}
+
+pub fn get_conference_for_group(sport_type: SportType, group: u64) -> Option<&'static Conference> {
+    CONFERENCES
+        .iter()
+        .find(|c| get_group(c, sport_type) == Some(group))
+}
+
+// What the scoreboard shows when no conference is asked for
+pub fn get_default_group(sport_type: SportType) -> u64 {
+    match sport_type {
+        SportType::Basketball => 50,
+        _ => 80,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::team::COLLEGE_TEAMS;
+    use crate::common::types::Sport;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_conference_sport_ids() {
+        let sport = Sport::from_str("college-football:big-ten").unwrap();
+        assert_eq!(sport.group, 5);
+        assert_eq!(sport.to_string(), "college-football:big-ten");
+        assert_eq!(
+            Sport::from_str("college-basketball:big-ten").unwrap().group,
+            7
+        );
+        assert_eq!(Sport::from_str("college-football:fcs").unwrap().group, 81);
+        assert_eq!(Sport::from_str("college-football:mvfc").unwrap().group, 21);
+        assert_eq!(
+            Sport::from_str("college-football:pioneer")
+                .unwrap()
+                .to_string(),
+            "college-football:pioneer"
+        );
+        assert!(Sport::from_str("college-basketball:mvfc").is_err());
+        assert!(Sport::from_str("college-basketball:fbs").is_err());
+        assert!(Sport::from_str("football:sec").is_err());
+
+        // Every Division I school in the catalog has a conference we can request. Hartford left
+        // Division I, so it has none.
+        for team in COLLEGE_TEAMS.values() {
+            assert!(
+                CONFERENCES.iter().any(|c| c.slug == team.conference) || team.id == 42,
+                "{team:?}"
+            );
+        }
+    }
+}
@@ -1,4 +1,34 @@
 use chrono::ParseError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FetchOptions {
+    pub golf_leaderboard_depth: usize,
+    // Golfers to always include on the leaderboard, matched on their full name
+    pub followed_golfers: Vec<String>,
+    // Turned off for deployments that must not show betting lines
+    pub include_odds: bool,
+    // Only keep college games with a ranked team in them
+    pub ranked_only: bool,
+    // Costs a summary request per pregame game, so only deployments that show it turn it on
+    pub include_predictor: bool,
+    // Also a summary request per live football game, made again only after the score changes
+    pub include_last_scoring_play: bool,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            golf_leaderboard_depth: 5,
+            followed_golfers: vec![],
+            include_odds: true,
+            ranked_only: false,
+            include_predictor: false,
+            include_last_scoring_play: false,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -1,50 +1,219 @@
-use chrono::{DateTime, NaiveDateTime};
+use chrono::DateTime;
 use futures::future::join_all;
+use futures::stream::{self, StreamExt};
 use itertools::Itertools;
+use lazy_static::lazy_static;
 use ordinal::Ordinal;
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
 
-use crate::common::team::{create_team, get_team_map};
+use crate::common::competition::{
+    get_broadcasts, get_leaders, get_odds, get_overtime_ordinal, get_postseason, get_predictor,
+    get_statsapi_postseason, get_status_and_time, get_venue, get_win_probability,
+};
+use crate::common::team::{create_team, get_competitor, get_statsapi_competitor, get_team_map};
 
-use crate::common::data::Error;
-use crate::common::proto_helpers::from_espn;
-use crate::common::types::game::SportData;
-use crate::common::types::{game::Status, sport::Level, sport::SportType, Game, Sport};
+use crate::common::box_score::{from_espn_summary, from_statsapi_box_score};
+use crate::common::bracket::{from_espn_postseason, from_statsapi_playoffs};
+use crate::common::conference::get_default_group;
+use crate::common::data::{Error, FetchOptions};
+use crate::common::news::{from_espn_news, to_ticker};
+use crate::common::plays::{from_espn_play, from_statsapi_play, get_espn_plays};
+use crate::common::polls::from_espn_rankings;
+use crate::common::proto_helpers::from_statsapi;
+use crate::common::schedule::{from_espn_schedule_event, get_nhl_season};
+use crate::common::standings::{from_espn_standings, from_statsapi_standings};
+use crate::common::types::game::football_data::ScoringPlay;
+use crate::common::types::game::{PeriodScore, SportData, WinProbability};
+use crate::common::types::{
+    game::Status, sport::League, sport::Level, sport::SportType, BoxScore, Bracket, Game, Play,
+    Poll, Sport, Standings,
+};
 
 use crate::common::processors::{
-    get_array, get_array_from_value, get_object, get_object_from_value, get_str,
-    get_str_from_value, get_u64, get_u64_from_value, get_u64_str, get_u64_str_from_value,
+    get_array, get_array_from_value, get_bool_from_value, get_f64_from_value, get_object,
+    get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_from_value, get_u64_str,
+    get_u64_str_from_value,
 };
 
 use crate::sport::baseball::get_baseball_data;
-use crate::sport::basketball::get_basketball_data;
-use crate::sport::football::get_football_data;
+use crate::sport::basketball::{get_basketball_data, get_basketball_ordinal};
+use crate::sport::football::{get_football_data, get_scoring_play};
 use crate::sport::golf::process_golf;
 use crate::sport::hockey::fetch_hockey;
+use crate::sport::mma::process_fights;
+use crate::sport::racing::process_racing;
+use crate::sport::tennis::process_tennis;
+
+const TENNIS_URLS: [&str; 2] = [
+    "http://site.api.espn.com/apis/site/v2/sports/tennis/atp/scoreboard",
+    "http://site.api.espn.com/apis/site/v2/sports/tennis/wta/scoreboard",
+];
+
+const RACING_URLS: [(&str, &str); 3] = [
+    (
+        "F1",
+        "http://site.api.espn.com/apis/site/v2/sports/racing/f1/scoreboard",
+    ),
+    (
+        "NASCAR",
+        "http://site.api.espn.com/apis/site/v2/sports/racing/nascar-premier/scoreboard",
+    ),
+    (
+        "INDYCAR",
+        "http://site.api.espn.com/apis/site/v2/sports/racing/irl/scoreboard",
+    ),
+];
+
+const MMA_URLS: [(&str, &str); 1] = [(
+    "UFC",
+    "http://site.api.espn.com/apis/site/v2/sports/mma/ufc/scoreboard",
+)];
 
 fn get_espn_url(sport: &Sport) -> &'static str {
     match (sport.sport_type(), sport.level()) {
         (SportType::Hockey, _) => panic!("Not allowed to use ESPN for hockey"),
+        (SportType::Tennis, _) => panic!("Tennis is fetched per tour"),
+        (SportType::Racing, _) => panic!("Racing is fetched per series"),
+        (SportType::Mma, _) => panic!("MMA is fetched per promotion"),
         (SportType::Baseball, _) => "http://site.api.espn.com/apis/site/v2/sports/baseball/mlb/scoreboard",
         (SportType::Football, Level::Professional) => "http://site.api.espn.com/apis/site/v2/sports/football/nfl/scoreboard",
-        (SportType::Football, Level::Collegiate) => "http://site.api.espn.com/apis/site/v2/sports/football/college-football/scoreboard?groups=80",
+        (SportType::Football, Level::Collegiate) => "http://site.api.espn.com/apis/site/v2/sports/football/college-football/scoreboard",
         (SportType::Basketball, Level::Professional) => "http://site.api.espn.com/apis/site/v2/sports/basketball/nba/scoreboard",
-        (SportType::Basketball, Level::Collegiate)=> "http://site.api.espn.com/apis/site/v2/sports/basketball/mens-college-basketball/scoreboard?groups=50",
-        (SportType::Golf, _)=> "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=pga",
+        (SportType::Basketball, Level::Collegiate)=> "http://site.api.espn.com/apis/site/v2/sports/basketball/mens-college-basketball/scoreboard",
+        (SportType::Golf, _) => match sport.league() {
+            League::Default => "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=pga",
+            League::Lpga => "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=lpga",
+            League::DpWorldTour => "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=eur",
+            League::Liv => "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=liv",
+            League::ChampionsTour => "http://site.api.espn.com/apis/site/v2/sports/golf/leaderboard?league=champions-tour",
+        },
+    }
+}
+
+fn get_espn_scoreboard_url(sport: &Sport) -> String {
+    match sport.level() {
+        Level::Collegiate => {
+            let group = match sport.group {
+                0 => get_default_group(sport.sport_type()),
+                group => group,
+            };
+            format!("{}?groups={group}", get_espn_url(sport))
+        }
+        Level::Professional => get_espn_url(sport).to_owned(),
     }
 }
 
-pub async fn fetch_espn(sport: &Sport) -> Result<Vec<Game>, Error> {
-    let url = get_espn_url(sport);
+// The league's API root, e.g. ".../sports/football/nfl/"
+fn get_espn_base_url(sport: &Sport) -> &'static str {
+    let scoreboard_url = get_espn_url(sport);
+    scoreboard_url
+        .split("scoreboard")
+        .next()
+        .unwrap_or(scoreboard_url)
+}
+
+fn get_espn_summary_url(sport: &Sport, game_id: u64) -> String {
+    format!("{}summary?event={game_id}", get_espn_base_url(sport))
+}
+
+fn get_espn_standings_url(sport: &Sport) -> String {
+    // Standings live under the v2 API rather than the site API
+    get_espn_base_url(sport).replace("/apis/site/v2/", "/apis/v2/") + "standings"
+}
+
+// Leagues fetched per tour or series pull news from each of them
+fn get_espn_news_urls(sport: &Sport) -> Vec<String> {
+    let news_url = |url: &str| url.replace("scoreboard", "news");
+    match sport.sport_type() {
+        SportType::Hockey => {
+            vec!["http://site.api.espn.com/apis/site/v2/sports/hockey/nhl/news".to_owned()]
+        }
+        SportType::Tennis => TENNIS_URLS.iter().map(|url| news_url(url)).collect(),
+        SportType::Racing => RACING_URLS.iter().map(|(_, url)| news_url(url)).collect(),
+        SportType::Mma => MMA_URLS.iter().map(|(_, url)| news_url(url)).collect(),
+        SportType::Golf => {
+            let league = get_espn_url(sport)
+                .split_once("league=")
+                .map_or("pga", |(_, league)| league);
+            vec![format!(
+                "http://site.api.espn.com/apis/site/v2/sports/golf/{league}/news"
+            )]
+        }
+        _ => vec![format!("{}news", get_espn_base_url(sport))],
+    }
+}
+
+async fn fetch_espn_json(url: &str) -> Result<Map<String, Value>, Error> {
     let resp = reqwest::get(url).await?.text().await?;
-    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+    Ok(serde_json::from_str(&resp)?)
+}
+
+pub async fn fetch_espn_tennis(sport: &Sport) -> Result<Vec<Game>, Error> {
+    let results = join_all(TENNIS_URLS.iter().map(|url| fetch_espn_json(url))).await;
+    let mut out_games = Vec::new();
+    for (url, json) in TENNIS_URLS.iter().zip(results) {
+        // One tour being down shouldn't blank the other
+        let games = json.and_then(|json| process_tennis(sport, get_array(&json, "events")?));
+        match games {
+            Ok(games) => {
+                tracing::info!("Got json for sport {:?} at url {url}", sport);
+                out_games.extend(games);
+            }
+            Err(e) => tracing::warn!("Failed to fetch matches at url {url}: {e}"),
+        }
+    }
+    Ok(out_games)
+}
+
+pub async fn fetch_espn_racing(sport: &Sport) -> Result<Vec<Game>, Error> {
+    let results = join_all(RACING_URLS.iter().map(|(_, url)| fetch_espn_json(url))).await;
+    let mut out_games = Vec::new();
+    for ((series, url), json) in RACING_URLS.iter().zip(results) {
+        // One series being down shouldn't blank the others
+        let games =
+            json.and_then(|json| process_racing(sport, series, get_array(&json, "events")?));
+        match games {
+            Ok(games) => {
+                tracing::info!("Got json for sport {:?} at url {url}", sport);
+                out_games.extend(games);
+            }
+            Err(e) => tracing::warn!("Failed to fetch races at url {url}: {e}"),
+        }
+    }
+    Ok(out_games)
+}
+
+pub async fn fetch_espn_mma(sport: &Sport) -> Result<Vec<Game>, Error> {
+    let results = join_all(MMA_URLS.iter().map(|(_, url)| fetch_espn_json(url))).await;
+    let mut out_games = Vec::new();
+    for ((promotion, url), json) in MMA_URLS.iter().zip(results) {
+        // One promotion being down shouldn't blank the others
+        let games =
+            json.and_then(|json| process_fights(sport, promotion, get_array(&json, "events")?));
+        match games {
+            Ok(games) => {
+                tracing::info!("Got json for sport {:?} at url {url}", sport);
+                out_games.extend(games);
+            }
+            Err(e) => tracing::warn!("Failed to fetch fights at url {url}: {e}"),
+        }
+    }
+    Ok(out_games)
+}
+
+pub async fn fetch_espn(sport: &Sport, options: &FetchOptions) -> Result<Vec<Game>, Error> {
+    let url = get_espn_scoreboard_url(sport);
+    let json = fetch_espn_json(&url).await?;
     tracing::info!("Got json for sport {:?} at url {url}", sport);
     let events = get_array(&json, "events")?;
 
     if sport.sport_type() == SportType::Golf {
         tracing::debug!("Doing golf stuff");
-        return process_golf(events);
+        return process_golf(sport, events, options);
     }
 
     let mut out_games = Vec::new();
@@ -59,25 +228,18 @@ pub async fn fetch_espn(sport: &Sport) -> Result<Vec<Game>, Error> {
             .iter()
             .collect_tuple()
             .ok_or("Failed to unwrap home team and away team")?;
+        // skip games > 12 hours ago or in the future
+        let (status, time) = match get_status_and_time(competition, Some(12))? {
+            Some(status_and_time) => status_and_time,
+            None => continue,
+        };
         let espn_status = get_str(get_object(status_object, "type")?, "name")?;
-        let status = from_espn(espn_status);
-        if status == Status::Invalid {
-            continue;
-        }
-
-        let time_str = get_str_from_value(competition, "date")?;
-        let time = NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%MZ")?;
-        let time: DateTime<chrono::Utc> = DateTime::from_utc(time, chrono::Utc);
-        let now = chrono::offset::Utc::now();
-
-        let delta_hours = now.signed_duration_since(time).num_hours().abs();
-        if delta_hours > 12 {
-            // skip games > 12 hours ago or in the future
-            continue;
-        }
 
         let period = get_u64(status_object, "period")?;
-        let mut ordinal = Ordinal(period).to_string();
+        let mut ordinal = match sport.sport_type() {
+            SportType::Basketball => get_basketball_ordinal(sport, period),
+            _ => Ordinal(period).to_string(),
+        };
         if status == Status::Intermission {
             ordinal += " INT";
         }
@@ -85,6 +247,18 @@ pub async fn fetch_espn(sport: &Sport) -> Result<Vec<Game>, Error> {
             ordinal = "HALFTIME".to_owned();
         }
 
+        let (home_competitor, away_competitor) = (
+            get_competitor(sport, home_team),
+            get_competitor(sport, away_team),
+        );
+        if options.ranked_only
+            && sport.level() == Level::Collegiate
+            && home_competitor.rank == 0
+            && away_competitor.rank == 0
+        {
+            continue;
+        }
+
         let team_map = get_team_map(sport);
         let home_id = get_u64_str(get_object_from_value(home_team, "team")?, "id")?;
         let away_id = get_u64_str(get_object_from_value(away_team, "team")?, "id")?;
@@ -114,18 +288,202 @@ pub async fn fetch_espn(sport: &Sport) -> Result<Vec<Game>, Error> {
                 ordinal,
                 start_time: time.timestamp_nanos(),
                 sport_data: None,
+                period_scores: get_period_scores(sport, home_team, away_team),
+                home_competitor: Some(home_competitor),
+                away_competitor: Some(away_competitor),
+                broadcasts: get_broadcasts(competition),
+                venue: get_venue(competition),
+                neutral_site: get_bool_from_value(competition, "neutralSite").unwrap_or(false),
+                odds: if options.include_odds {
+                    get_odds(competition)
+                } else {
+                    None
+                },
+                win_probability: get_win_probability(competition),
+                postseason: get_postseason(event, competition),
+                leaders: get_leaders(competition, home_id, away_id),
             };
             g.sport_data = Some(get_extra_data(competition, &g)?);
             g
         };
         out_games.push(out_game)
     }
+
+    if options.include_last_scoring_play && sport.sport_type() == SportType::Football {
+        out_games = stream::iter(out_games.into_iter().map(add_last_scoring_play))
+            .buffered(MAX_SUMMARY_REQUESTS)
+            .collect()
+            .await;
+    }
+    // ESPN only runs its pregame predictor for football and basketball
+    if options.include_predictor
+        && matches!(
+            sport.sport_type(),
+            SportType::Football | SportType::Basketball
+        )
+    {
+        out_games = stream::iter(out_games.into_iter().map(add_predictor))
+            .buffered(MAX_SUMMARY_REQUESTS)
+            .collect()
+            .await;
+    }
     Ok(out_games)
 }
 
-pub async fn fetch_statsapi(sport: &Sport) -> Result<Vec<Game>, Error> {
+// Pregame predictions barely move, so each game's summary is fetched at most this often
+const PREDICTOR_TTL: Duration = Duration::from_secs(30 * 60);
+// A full college slate has over a hundred games, don't fire all of their summaries at once
+const MAX_SUMMARY_REQUESTS: usize = 8;
+
+type PredictorCache = HashMap<(Sport, u64), (Instant, Option<WinProbability>)>;
+
+lazy_static! {
+    static ref PREDICTOR_CACHE: RwLock<PredictorCache> = RwLock::new(PredictorCache::new());
+}
+
+async fn add_predictor(mut game: Game) -> Game {
+    if game.status() != Status::Pregame {
+        return game;
+    }
+    let sport = game.sport.unwrap_or_default();
+    let key = (sport, game.game_id);
+    let cached = PREDICTOR_CACHE.read().ok().and_then(|cache| {
+        cache
+            .get(&key)
+            .filter(|(updated, _)| updated.elapsed() < PREDICTOR_TTL)
+            .map(|(_, predictor)| predictor.clone())
+    });
+    if let Some(predictor) = cached {
+        game.win_probability = predictor;
+        return game;
+    }
+
+    match fetch_espn_json(&get_espn_summary_url(&sport, game.game_id)).await {
+        Ok(summary) => {
+            game.win_probability = get_predictor(&summary);
+            if let Ok(mut cache) = PREDICTOR_CACHE.write() {
+                cache.retain(|_, (updated, _)| updated.elapsed() < PREDICTOR_TTL);
+                cache.insert(key, (Instant::now(), game.win_probability.clone()));
+            }
+        }
+        // The predictor is a nice to have, so keep the game without it
+        Err(e) => tracing::warn!("Failed to fetch predictor for game {}: {e}", game.game_id),
+    }
+    game
+}
+
+// The last scoring play only changes with the score, so a game's summary is fetched again only
+// once its score moves. Entries for games that have gone quiet this long are dropped.
+const SCORING_PLAY_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+
+type ScoringPlayCache = HashMap<(Sport, u64), (Instant, (u64, u64), ScoringPlay)>;
+
+lazy_static! {
+    static ref SCORING_PLAY_CACHE: RwLock<ScoringPlayCache> = RwLock::new(ScoringPlayCache::new());
+}
+
+async fn add_last_scoring_play(mut game: Game) -> Game {
+    if !matches!(game.status(), Status::Active | Status::Intermission) {
+        return game;
+    }
+    let sport = game.sport.unwrap_or_default();
+    let key = (sport, game.game_id);
+    let score = (game.home_team_score, game.away_team_score);
+    let cached = SCORING_PLAY_CACHE.read().ok().and_then(|cache| {
+        cache
+            .get(&key)
+            .filter(|(_, cached_score, _)| *cached_score == score)
+            .map(|(_, _, scoring_play)| *scoring_play)
+    });
+    let scoring_play = match cached {
+        Some(scoring_play) => scoring_play,
+        None => match fetch_espn_json(&get_espn_summary_url(&sport, game.game_id)).await {
+            Ok(summary) => {
+                let scoring_play = get_espn_plays(&summary, true)
+                    .last()
+                    .and_then(|play| play.as_object())
+                    .map(get_scoring_play)
+                    .unwrap_or(ScoringPlay::NoScore);
+                if let Ok(mut cache) = SCORING_PLAY_CACHE.write() {
+                    cache.retain(|_, (updated, _, _)| updated.elapsed() < SCORING_PLAY_TTL);
+                    cache.insert(key, (Instant::now(), score, scoring_play));
+                }
+                scoring_play
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to fetch scoring plays for game {}: {e}",
+                    game.game_id
+                );
+                return game;
+            }
+        },
+    };
+    if let Some(SportData::FootballData(football_data)) = &mut game.sport_data {
+        football_data.last_scoring_play = scoring_play.into();
+    }
+    game
+}
+
+// Postponed games come back as None
+fn from_statsapi_game(sport: &Sport, game: &Value) -> Result<Option<Game>, Error> {
     let team_map = get_team_map(sport);
-    let schedule_url = "http://statsapi.web.nhl.com/api/v1/schedule";
+    let status = get_object_from_value(game, "status")?;
+    let detailed_state = status
+        .get("detailedState")
+        .ok_or("No detailed state present")?;
+    let status = from_statsapi(get_str(status, "abstractGameState")?);
+    if detailed_state == "Postponed" || status == Status::Invalid {
+        return Ok(None);
+    }
+    let game_date = get_str_from_value(game, "gameDate")?;
+    let game_id = get_u64_from_value(game, "gamePk")?;
+
+    let teams = get_object_from_value(game, "teams")?;
+    let away = teams.get("away").ok_or("No away team present")?;
+    let home = teams.get("home").ok_or("No home team present")?;
+    let away_team_id = get_u64(get_object_from_value(away, "team")?, "id")?;
+    let home_team_id = get_u64(get_object_from_value(home, "team")?, "id")?;
+
+    // Exhibitions against clubs from outside the league have no entry in the catalog
+    let (away_team, home_team) = match (team_map.get(&away_team_id), team_map.get(&home_team_id)) {
+        (Some(away_team), Some(home_team)) => (away_team, home_team),
+        _ => {
+            tracing::warn!(
+                "Skipping game {game_id}, team {away_team_id} or {home_team_id} is not in the catalog"
+            );
+            return Ok(None);
+        }
+    };
+
+    Ok(Some(Game {
+        game_id,
+        sport: Some(*sport),
+        home_team: Some(home_team.clone()),
+        away_team: Some(away_team.clone()),
+        home_team_score: get_u64_from_value(home, "score").unwrap_or(0),
+        away_team_score: get_u64_from_value(away, "score").unwrap_or(0),
+        period: 0,
+        status: status.into(), // Intermissions are picked up from the linescore
+        ordinal: String::new(),
+        start_time: DateTime::<chrono::Utc>::from_str(game_date)?.timestamp_nanos(),
+        sport_data: None,
+        period_scores: vec![],
+        home_competitor: Some(get_statsapi_competitor(home)),
+        away_competitor: Some(get_statsapi_competitor(away)),
+        broadcasts: vec![],
+        venue: None,
+        neutral_site: false,
+        odds: None,
+        win_probability: None,
+        postseason: get_statsapi_postseason(game),
+        leaders: vec![],
+    }))
+}
+
+pub async fn fetch_statsapi(sport: &Sport) -> Result<Vec<Game>, Error> {
+    let schedule_url =
+        "http://statsapi.web.nhl.com/api/v1/schedule?expand=schedule.game.seriesSummary.series";
 
     let resp = reqwest::get(schedule_url).await?.text().await?;
     let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
@@ -138,41 +496,7 @@ pub async fn fetch_statsapi(sport: &Sport) -> Result<Vec<Game>, Error> {
         let games = get_array_from_value(today, "games")?;
 
         for game in games {
-            let status = get_object_from_value(game, "status")?;
-            let detailed_state = status
-                .get("detailedState")
-                .ok_or("No detailed state present")?;
-            if detailed_state == "Postponed" {
-                continue;
-            } else {
-                let game_date = get_str_from_value(game, "gameDate")?;
-                println!("Got dame date {game_date}");
-                let game_id = get_u64_from_value(game, "gamePk")?;
-
-                let teams = get_object_from_value(game, "teams")?;
-                let away_team_id = get_u64(get_object(get_object(teams, "away")?, "team")?, "id")?;
-                let home_team_id = get_u64(get_object(get_object(teams, "home")?, "team")?, "id")?;
-
-                let away_team = team_map
-                    .get(&away_team_id)
-                    .ok_or(format!("Away team '{away_team_id}' not present"))?;
-                let home_team = team_map
-                    .get(&home_team_id)
-                    .ok_or(format!("Home team '{home_team_id}' not present"))?;
-
-                let g = Game {
-                    game_id,
-                    sport: Some(*sport),
-                    home_team: Some(home_team.clone()),
-                    away_team: Some(away_team.clone()),
-                    home_team_score: 0,
-                    away_team_score: 0,
-                    period: 0,
-                    status: Status::Active.into(), // Will be corrected later
-                    ordinal: String::new(),
-                    start_time: DateTime::<chrono::Utc>::from_str(game_date)?.timestamp_nanos(),
-                    sport_data: None,
-                };
+            if let Some(g) = from_statsapi_game(sport, game)? {
                 out_games.push(g);
             }
         }
@@ -181,11 +505,238 @@ pub async fn fetch_statsapi(sport: &Sport) -> Result<Vec<Game>, Error> {
     results.into_iter().collect()
 }
 
+pub async fn fetch_espn_plays(
+    sport: &Sport,
+    game_id: u64,
+    scoring_only: bool,
+) -> Result<Vec<Play>, Error> {
+    let summary = fetch_espn_json(&get_espn_summary_url(sport, game_id)).await?;
+    tracing::info!("Got summary for {sport} game {game_id}");
+    get_espn_plays(&summary, scoring_only)
+        .into_iter()
+        .map(from_espn_play)
+        .collect()
+}
+
+pub async fn fetch_statsapi_plays(game_id: u64, scoring_only: bool) -> Result<Vec<Play>, Error> {
+    let url = format!("http://statsapi.web.nhl.com/api/v1/game/{game_id}/playByPlay");
+    let resp = reqwest::get(url).await?.text().await?;
+    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+
+    let plays = get_array(&json, "allPlays")?
+        .iter()
+        .map(from_statsapi_play)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(plays
+        .into_iter()
+        .filter(|play| !scoring_only || play.is_scoring_play)
+        .collect())
+}
+
+pub async fn fetch_espn_box_score(sport: &Sport, game_id: u64) -> Result<BoxScore, Error> {
+    let summary = fetch_espn_json(&get_espn_summary_url(sport, game_id)).await?;
+    tracing::info!("Got summary for {sport} game {game_id}");
+    from_espn_summary(&summary, game_id)
+}
+
+pub async fn fetch_statsapi_box_score(game_id: u64) -> Result<BoxScore, Error> {
+    let url = format!("http://statsapi.web.nhl.com/api/v1/game/{game_id}/boxscore");
+    let resp = reqwest::get(url).await?.text().await?;
+    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+    from_statsapi_box_score(&json, game_id)
+}
+
+pub async fn fetch_espn_standings(sport: &Sport) -> Result<Standings, Error> {
+    let url = get_espn_standings_url(sport);
+    let json = fetch_espn_json(&url).await?;
+    tracing::info!("Got standings for sport {:?} at url {url}", sport);
+    Ok(Standings {
+        sport: Some(*sport),
+        groups: from_espn_standings(&json)?,
+    })
+}
+
+pub async fn fetch_statsapi_standings(sport: &Sport) -> Result<Standings, Error> {
+    let url = "http://statsapi.web.nhl.com/api/v1/standings";
+    let resp = reqwest::get(url).await?.text().await?;
+    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+    Ok(Standings {
+        sport: Some(*sport),
+        groups: from_statsapi_standings(&json)?,
+    })
+}
+
+pub async fn fetch_espn_team_schedule(sport: &Sport, team_id: u64) -> Result<Vec<Game>, Error> {
+    let url = format!("{}teams/{team_id}/schedule", get_espn_base_url(sport));
+    let json = fetch_espn_json(&url).await?;
+    tracing::info!("Got schedule for {sport} team {team_id}");
+
+    let mut out_games = Vec::new();
+    for event in get_array(&json, "events")? {
+        if let Some(game) = from_espn_schedule_event(sport, event)? {
+            out_games.push(game);
+        }
+    }
+    Ok(out_games)
+}
+
+pub async fn fetch_statsapi_team_schedule(sport: &Sport, team_id: u64) -> Result<Vec<Game>, Error> {
+    let url = format!(
+        "http://statsapi.web.nhl.com/api/v1/schedule?teamId={team_id}&season={}",
+        get_nhl_season()
+    );
+    let resp = reqwest::get(url).await?.text().await?;
+    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+
+    let mut out_games = Vec::new();
+    for date in get_array(&json, "dates")? {
+        for game in get_array_from_value(date, "games")? {
+            if let Some(game) = from_statsapi_game(sport, game)? {
+                out_games.push(game);
+            }
+        }
+    }
+    Ok(out_games)
+}
+
+pub async fn fetch_espn_polls(sport: &Sport) -> Result<Vec<Poll>, Error> {
+    let url = format!("{}rankings", get_espn_base_url(sport));
+    let json = fetch_espn_json(&url).await?;
+    tracing::info!("Got polls for sport {:?} at url {url}", sport);
+    from_espn_rankings(&json)
+}
+
+pub async fn fetch_espn_headlines(sport: &Sport, max_length: usize) -> Result<Vec<String>, Error> {
+    let urls = get_espn_news_urls(sport);
+    let results = join_all(urls.iter().map(|url| fetch_espn_json(url))).await;
+    let mut headlines = Vec::new();
+    for (url, json) in urls.iter().zip(results) {
+        // One tour's feed being down shouldn't blank the whole ticker
+        match json.and_then(|json| from_espn_news(sport, &json)) {
+            Ok(news) => {
+                tracing::info!("Got news for sport {:?} at url {url}", sport);
+                headlines.extend(news);
+            }
+            Err(e) => tracing::warn!("Failed to fetch news at url {url}: {e}"),
+        }
+    }
+    Ok(to_ticker(headlines, max_length))
+}
+
+pub async fn fetch_espn_bracket(sport: &Sport) -> Result<Bracket, Error> {
+    // Postseasons run a couple of months, so look back far enough to catch the first round
+    let now = chrono::offset::Utc::now();
+    let start = (now - chrono::Duration::days(90)).format("%Y%m%d");
+    let end = (now + chrono::Duration::days(30)).format("%Y%m%d");
+    let url = get_espn_scoreboard_url(sport);
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{url}{separator}seasontype=3&dates={start}-{end}&limit=1000");
+
+    let json = fetch_espn_json(&url).await?;
+    tracing::info!("Got postseason for sport {:?} at url {url}", sport);
+    Ok(Bracket {
+        sport: Some(*sport),
+        rounds: from_espn_postseason(sport, get_array(&json, "events")?)?,
+    })
+}
+
+pub async fn fetch_statsapi_bracket(sport: &Sport) -> Result<Bracket, Error> {
+    let url = format!(
+        "http://statsapi.web.nhl.com/api/v1/tournaments/playoffs?expand=round.series,schedule.game.seriesSummary&season={}",
+        get_nhl_season()
+    );
+    let resp = reqwest::get(url).await?.text().await?;
+    let json: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&resp)?;
+    Ok(Bracket {
+        sport: Some(*sport),
+        rounds: from_statsapi_playoffs(&json)?,
+    })
+}
+
+// Innings are just numbered, other periods read like the game ordinal
+fn get_period_label(sport: &Sport, period: u64) -> String {
+    match sport.sport_type() {
+        SportType::Basketball => get_basketball_ordinal(sport, period),
+        SportType::Football => get_overtime_ordinal(period, 4),
+        _ => period.to_string(),
+    }
+}
+
+fn get_period_scores(sport: &Sport, home_team: &Value, away_team: &Value) -> Vec<PeriodScore> {
+    let get_scores = |competitor| -> Vec<u64> {
+        get_array_from_value(competitor, "linescores")
+            .map(|periods| {
+                periods
+                    .iter()
+                    .map(|p| get_f64_from_value(p, "value").unwrap_or(0.0) as u64)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    // In baseball the home team is an inning short until they bat in the bottom half
+    let (home_scores, away_scores) = (get_scores(home_team), get_scores(away_team));
+    (0..home_scores.len().max(away_scores.len()))
+        .map(|i| {
+            let period = i as u64 + 1;
+            PeriodScore {
+                period,
+                label: get_period_label(sport, period),
+                home_score: home_scores.get(i).copied().unwrap_or(0),
+                away_score: away_scores.get(i).copied().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
 fn get_extra_data(competition: &Value, game: &Game) -> Result<SportData, Error> {
     match game.sport.unwrap().sport_type() {
         SportType::Baseball => get_baseball_data(competition),
         SportType::Football => get_football_data(competition, game),
-        SportType::Basketball => get_basketball_data(competition),
-        SportType::Hockey | SportType::Golf => unreachable!(),
+        SportType::Basketball => get_basketball_data(competition, game),
+        SportType::Hockey
+        | SportType::Golf
+        | SportType::Tennis
+        | SportType::Racing
+        | SportType::Mma => {
+            unreachable!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use serde_json::json;
+
+    fn statsapi_game(away_id: u64) -> Value {
+        json!({
+            "gamePk": 2023020001,
+            "gameDate": "2023-10-10T23:00:00Z",
+            "gameType": "R",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "teams": {
+                "away": {"score": 2, "team": {"id": away_id}},
+                "home": {"score": 5, "team": {"id": 6}},
+            },
+        })
+    }
+
+    #[test]
+    fn test_from_statsapi_game() {
+        let sport = new_sport(SportType::Hockey, Level::Professional);
+        let game = from_statsapi_game(&sport, &statsapi_game(5))
+            .unwrap()
+            .unwrap();
+        assert_eq!(game.game_id, 2023020001);
+        assert_eq!(game.status(), Status::End);
+        assert_eq!(game.home_team.unwrap().abbreviation, "BOS");
+        assert_eq!(game.away_team.unwrap().abbreviation, "PIT");
+        assert_eq!((game.home_team_score, game.away_team_score), (5, 2));
+
+        // An opponent from outside the league skips the game rather than failing the schedule
+        assert!(from_statsapi_game(&sport, &statsapi_game(99999))
+            .unwrap()
+            .is_none());
     }
 }
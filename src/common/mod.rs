@@ -1,10 +1,20 @@
+pub mod box_score;
+pub mod bracket;
 pub mod color;
+pub mod competition;
+pub mod conference;
 pub mod data;
 pub mod fetch;
+pub mod news;
+pub mod plays;
+pub mod polls;
 pub mod processors;
 pub mod proto_helpers;
+pub mod schedule;
+pub mod standings;
 pub mod team;
 
+#[allow(clippy::large_enum_variant)]
 pub mod types {
     include!(concat!(env!("OUT_DIR"), "/common.types.rs"));
 }
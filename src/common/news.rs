@@ -0,0 +1,118 @@
+use itertools::Itertools;
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{get_array, get_str_from_value};
+use crate::common::types::sport::SportType;
+use crate::common::types::Sport;
+use crate::sport::golf::shorten_headline;
+
+// Cuts at the last whole word that fits, leaving room for the ellipsis
+fn truncate(headline: &str, max_length: usize) -> String {
+    if headline.chars().count() <= max_length {
+        return headline.to_owned();
+    }
+    if max_length <= 3 {
+        // No room for the ellipsis
+        return headline.chars().take(max_length).collect();
+    }
+    let mut out = String::new();
+    for word in headline.split(' ') {
+        let length = out.chars().count() + word.chars().count() + usize::from(!out.is_empty());
+        if length + 3 > max_length {
+            break;
+        }
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+    if out.is_empty() {
+        // A single word longer than the ticker, just chop it
+        out = headline.chars().take(max_length - 3).collect();
+    }
+    out + "..."
+}
+
+// Only golf headlines get the tour name shortening, everything else is just uppercased
+fn to_headline(sport: &Sport, headline: &str) -> String {
+    match sport.sport_type() {
+        SportType::Golf => shorten_headline(headline),
+        _ => headline.split_whitespace().join(" ").to_uppercase(),
+    }
+}
+
+pub fn from_espn_news(sport: &Sport, json: &Map<String, Value>) -> Result<Vec<String>, Error> {
+    Ok(get_array(json, "articles")?
+        .iter()
+        .filter_map(|article| get_str_from_value(article, "headline").ok())
+        .map(|headline| to_headline(sport, headline))
+        .filter(|headline| !headline.is_empty())
+        .collect())
+}
+
+// Feeds from different tours often repeat the same story. Dedupe what ends up on the ticker, since
+// headlines that only differ past max_length look the same once cut.
+pub fn to_ticker(headlines: Vec<String>, max_length: usize) -> Vec<String> {
+    headlines
+        .into_iter()
+        .map(|headline| truncate(&headline, max_length))
+        .unique()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use crate::common::types::sport::Level;
+
+    #[test]
+    fn test_to_ticker() {
+        let headlines = [
+            "Scheffler wins the Genesis Scottish Open",
+            "Scheffler wins the Genesis Scottish Open",
+            "Fourteen fifteen sixteen seventeen",
+        ]
+        .map(shorten_headline)
+        .to_vec();
+        assert_eq!(
+            to_ticker(headlines, 32),
+            vec![
+                "SCHEFFLER WINS THE SCOTTISH OPEN",
+                "FOURTEEN FIFTEEN SIXTEEN..."
+            ]
+        );
+        assert_eq!(
+            to_ticker(vec!["ABCDEFGHIJ".to_owned()], 8),
+            vec!["ABCDE..."]
+        );
+        assert_eq!(to_ticker(vec!["ABCDEFGHIJ".to_owned()], 2), vec!["AB"]);
+        assert_eq!(
+            to_ticker(
+                vec![
+                    "OPEN FIELD SET AS RAIN DELAYS ROUND ONE".to_owned(),
+                    "OPEN FIELD SET AS RAIN DELAYS ROUND TWO".to_owned(),
+                ],
+                20
+            ),
+            vec!["OPEN FIELD SET AS..."]
+        );
+    }
+
+    #[test]
+    fn test_from_espn_news() {
+        let json = serde_json::json!({
+            "articles": [
+                { "headline": "Celtics  win championship" },
+                { "headline": "Open   favorite withdraws" },
+                { "description": "No headline" },
+            ]
+        });
+        let sport = new_sport(SportType::Basketball, Level::Professional);
+        assert_eq!(
+            from_espn_news(&sport, json.as_object().unwrap()).unwrap(),
+            vec!["CELTICS WIN CHAMPIONSHIP", "OPEN FAVORITE WITHDRAWS"]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array, get_array_from_value, get_bool_from_value, get_object, get_object_from_value,
+    get_str, get_str_from_value, get_u64, get_u64_from_value, get_u64_str,
+};
+use crate::common::types::Play;
+
+pub fn get_espn_plays(summary: &Map<String, Value>, scoring_only: bool) -> Vec<&Value> {
+    if scoring_only {
+        return get_array(summary, "scoringPlays")
+            .map(|plays| plays.iter().collect())
+            .unwrap_or_default();
+    }
+    if let Ok(plays) = get_array(summary, "plays") {
+        return plays.iter().collect();
+    }
+    // Football nests its plays inside each drive
+    let mut plays = vec![];
+    if let Ok(drives) = get_object(summary, "drives") {
+        for drive in get_array(drives, "previous")
+            .map(|d| d.as_slice())
+            .unwrap_or_default()
+        {
+            plays.extend(
+                get_array_from_value(drive, "plays")
+                    .map(|p| p.as_slice())
+                    .unwrap_or_default(),
+            );
+        }
+        if let Some(current) = drives.get("current") {
+            let current_plays = get_array_from_value(current, "plays")
+                .map(|p| p.as_slice())
+                .unwrap_or_default();
+            // The current drive is also the last of the previous drives once it has ended
+            if let Some(first) = current_plays.first() {
+                if !plays.contains(&first) {
+                    plays.extend(current_plays);
+                }
+            }
+        }
+    }
+    plays
+}
+
+pub fn from_espn_play(play: &Value) -> Result<Play, Error> {
+    Ok(Play {
+        play_id: get_str_from_value(play, "id")?.to_owned(),
+        period: get_object_from_value(play, "period")
+            .and_then(|period| get_u64(period, "number"))
+            .unwrap_or(0),
+        clock: get_object_from_value(play, "clock")
+            .and_then(|clock| get_str(clock, "displayValue"))
+            .unwrap_or_default()
+            .to_owned(),
+        team_id: get_object_from_value(play, "team")
+            .and_then(|team| get_u64_str(team, "id"))
+            .unwrap_or(0),
+        description: get_str_from_value(play, "text")
+            .unwrap_or_default()
+            .to_owned(),
+        play_type: get_object_from_value(play, "type")
+            .and_then(|t| get_str(t, "text"))
+            .unwrap_or_default()
+            .to_owned(),
+        home_score: get_u64_from_value(play, "homeScore").unwrap_or(0),
+        away_score: get_u64_from_value(play, "awayScore").unwrap_or(0),
+        // The scoringPlays list doesn't carry the flag, everything in it scored
+        is_scoring_play: get_bool_from_value(play, "scoringPlay").unwrap_or(true),
+    })
+}
+
+pub fn from_statsapi_play(play: &Value) -> Result<Play, Error> {
+    let result = get_object_from_value(play, "result")?;
+    let about = get_object_from_value(play, "about")?;
+    let goals = get_object(about, "goals").ok();
+    Ok(Play {
+        play_id: get_u64(about, "eventIdx")?.to_string(),
+        period: get_u64(about, "period").unwrap_or(0),
+        clock: get_str(about, "periodTimeRemaining")
+            .unwrap_or_default()
+            .to_owned(),
+        team_id: get_object_from_value(play, "team")
+            .and_then(|team| get_u64(team, "id"))
+            .unwrap_or(0),
+        description: get_str(result, "description")
+            .unwrap_or_default()
+            .to_owned(),
+        play_type: get_str(result, "event").unwrap_or_default().to_owned(),
+        home_score: goals.and_then(|g| get_u64(g, "home").ok()).unwrap_or(0),
+        away_score: goals.and_then(|g| get_u64(g, "away").ok()).unwrap_or(0),
+        is_scoring_play: get_str(result, "eventTypeId").is_ok_and(|t| t == "GOAL"),
+    })
+}
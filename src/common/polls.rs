@@ -0,0 +1,54 @@
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array, get_array_from_value, get_f64_from_value, get_object_from_value, get_str_from_value,
+    get_u64_from_value, get_u64_str,
+};
+use crate::common::types::poll::{Entry, PollType};
+use crate::common::types::Poll;
+
+fn get_poll_type(poll: &Value) -> PollType {
+    match get_str_from_value(poll, "type") {
+        Ok("ap") => PollType::Ap,
+        Ok("usa") => PollType::Coaches,
+        Ok("cfp") => PollType::Cfp,
+        _ => PollType::Other,
+    }
+}
+
+fn from_espn_rank(rank: &Value) -> Result<Entry, Error> {
+    let current = get_u64_from_value(rank, "current")?;
+    let previous_rank = get_u64_from_value(rank, "previous").unwrap_or(0);
+    Ok(Entry {
+        team_id: get_u64_str(get_object_from_value(rank, "team")?, "id")?,
+        rank: current,
+        previous_rank,
+        movement: if previous_rank > 0 {
+            previous_rank as i64 - current as i64
+        } else {
+            0
+        },
+        first_place_votes: get_u64_from_value(rank, "firstPlaceVotes").unwrap_or(0),
+        points: get_f64_from_value(rank, "points").unwrap_or(0.0),
+        record: get_str_from_value(rank, "recordSummary")
+            .unwrap_or_default()
+            .to_owned(),
+    })
+}
+
+pub fn from_espn_rankings(json: &Map<String, Value>) -> Result<Vec<Poll>, Error> {
+    get_array(json, "rankings")?
+        .iter()
+        .map(|poll| {
+            Ok(Poll {
+                poll_type: get_poll_type(poll).into(),
+                name: get_str_from_value(poll, "name")?.to_owned(),
+                entries: get_array_from_value(poll, "ranks")?
+                    .iter()
+                    .map(from_espn_rank)
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
@@ -45,6 +45,36 @@ pub fn get_u64_from_value(object: &Value, name: &'static str) -> Result<u64, Err
     Ok(num)
 }
 
+pub fn get_i64_from_value(object: &Value, name: &'static str) -> Result<i64, Error> {
+    let value = object
+        .get(name)
+        .ok_or(format!("{name} not present in {object}"))?;
+    let num = value.as_i64().ok_or(format!(
+        "{name} is not an integer {value:?}\nObject is: {object}"
+    ))?;
+    Ok(num)
+}
+
+pub fn get_f64_from_value(object: &Value, name: &'static str) -> Result<f64, Error> {
+    let value = object
+        .get(name)
+        .ok_or(format!("{name} not present in {object}"))?;
+    let num = value.as_f64().ok_or(format!(
+        "{name} is not a number {value:?}\nObject is: {object}"
+    ))?;
+    Ok(num)
+}
+
+pub fn get_bool_from_value(object: &Value, name: &'static str) -> Result<bool, Error> {
+    let value = object
+        .get(name)
+        .ok_or(format!("{name} not present in {object}"))?;
+    let bool = value.as_bool().ok_or(format!(
+        "{name} is not a bool {value:?}\nObject is: {object}"
+    ))?;
+    Ok(bool)
+}
+
 pub fn get_u64_str_from_value(object: &Value, name: &'static str) -> Result<u64, Error> {
     let value = object
         .get(name)
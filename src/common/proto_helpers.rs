@@ -1,8 +1,10 @@
+use std::fmt;
 use std::str::FromStr;
 
+use crate::common::conference::{get_conference_for_group, get_group_id};
 use crate::common::data::Error;
 use crate::common::types::game::Status;
-use crate::common::types::sport::{Level, SportType};
+use crate::common::types::sport::{League, Level, SportType};
 use crate::common::types::Sport;
 
 pub fn new_sport(sport_type: SportType, level: Level) -> Sport {
@@ -12,11 +14,24 @@ pub fn new_sport(sport_type: SportType, level: Level) -> Sport {
     sport
 }
 
+pub fn new_golf_tour(league: League) -> Sport {
+    let mut sport = new_sport(SportType::Golf, Level::Professional);
+    sport.set_league(league);
+    sport
+}
+
 pub fn all_sports() -> Vec<Sport> {
     vec![
         new_sport(SportType::Hockey, Level::Professional),
         new_sport(SportType::Baseball, Level::Professional),
-        new_sport(SportType::Golf, Level::Professional),
+        new_golf_tour(League::Default),
+        new_golf_tour(League::Lpga),
+        new_golf_tour(League::DpWorldTour),
+        new_golf_tour(League::Liv),
+        new_golf_tour(League::ChampionsTour),
+        new_sport(SportType::Tennis, Level::Professional),
+        new_sport(SportType::Racing, Level::Professional),
+        new_sport(SportType::Mma, Level::Professional),
         new_sport(SportType::Basketball, Level::Professional),
         new_sport(SportType::Basketball, Level::Collegiate),
         new_sport(SportType::Football, Level::Professional),
@@ -24,17 +39,30 @@ pub fn all_sports() -> Vec<Sport> {
     ]
 }
 
-impl ToString for Sport {
-    fn to_string(&self) -> String {
-        match (self.sport_type(), self.level()) {
-            (SportType::Hockey, Level::Professional) => "hockey".to_string(),
-            (SportType::Baseball, Level::Professional) => "baseball".to_string(),
-            (SportType::Golf, Level::Professional) => "golf".to_string(),
-            (SportType::Basketball, Level::Professional) => "basketball".to_string(),
-            (SportType::Basketball, Level::Collegiate) => "college-basketball".to_string(),
-            (SportType::Football, Level::Professional) => "football".to_string(),
-            (SportType::Football, Level::Collegiate) => "college-football".to_string(),
-            _ => "".to_string(),
+impl fmt::Display for Sport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match (self.sport_type(), self.level()) {
+            (SportType::Hockey, Level::Professional) => "hockey",
+            (SportType::Baseball, Level::Professional) => "baseball",
+            (SportType::Golf, Level::Professional) => match self.league() {
+                League::Default => "golf",
+                League::Lpga => "golf-lpga",
+                League::DpWorldTour => "golf-dp-world",
+                League::Liv => "golf-liv",
+                League::ChampionsTour => "golf-champions",
+            },
+            (SportType::Tennis, Level::Professional) => "tennis",
+            (SportType::Racing, Level::Professional) => "racing",
+            (SportType::Mma, Level::Professional) => "mma",
+            (SportType::Basketball, Level::Professional) => "basketball",
+            (SportType::Basketball, Level::Collegiate) => "college-basketball",
+            (SportType::Football, Level::Professional) => "football",
+            (SportType::Football, Level::Collegiate) => "college-football",
+            _ => "",
+        };
+        match get_conference_for_group(self.sport_type(), self.group) {
+            Some(conference) if self.group != 0 => write!(f, "{s}:{}", conference.slug),
+            _ => write!(f, "{s}"),
         }
     }
 }
@@ -43,8 +71,25 @@ impl FromStr for Sport {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // College sports can be narrowed to a conference, e.g. "college-football:big-ten"
+        if let Some((sport, conference)) = s.split_once(':') {
+            let mut sport = Sport::from_str(sport)?;
+            if sport.level() != Level::Collegiate {
+                return Err(Error::InvalidSportType(s.to_string()));
+            }
+            sport.group = get_group_id(sport.sport_type(), conference)
+                .ok_or_else(|| Error::InvalidSportType(s.to_string()))?;
+            return Ok(sport);
+        }
         match s {
-            "golf" => Ok(new_sport(SportType::Golf, Level::Professional)),
+            "golf" => Ok(new_golf_tour(League::Default)),
+            "golf-lpga" => Ok(new_golf_tour(League::Lpga)),
+            "golf-dp-world" => Ok(new_golf_tour(League::DpWorldTour)),
+            "golf-liv" => Ok(new_golf_tour(League::Liv)),
+            "golf-champions" => Ok(new_golf_tour(League::ChampionsTour)),
+            "tennis" => Ok(new_sport(SportType::Tennis, Level::Professional)),
+            "racing" => Ok(new_sport(SportType::Racing, Level::Professional)),
+            "mma" => Ok(new_sport(SportType::Mma, Level::Professional)),
             "baseball" => Ok(new_sport(SportType::Baseball, Level::Professional)),
             "hockey" => Ok(new_sport(SportType::Hockey, Level::Professional)),
             "football" => Ok(new_sport(SportType::Football, Level::Professional)),
@@ -59,10 +104,47 @@ impl FromStr for Sport {
 pub fn from_espn(input: &str) -> Status {
     match input {
         "STATUS_IN_PROGRESS" => Status::Active,
-        "STATUS_FINAL" | "STATUS_PLAY_COMPLETE" => Status::End,
+        "STATUS_FINAL"
+        | "STATUS_PLAY_COMPLETE"
+        | "STATUS_RETIRED"
+        | "STATUS_WALKOVER"
+        | "STATUS_FORFEIT" => Status::End,
         "STATUS_SCHEDULED" | "STATUS_RAIN_DELAY" => Status::Pregame,
-        "STATUS_END_PERIOD" | "STATUS_HALFTIME" | "STATUS_DELAYED" => Status::Intermission,
-        "STATUS_POSTPONED" | "STATUS_CANCELED" => Status::Invalid,
-        _ => panic!("Unknown status {input}"),
+        // Fighters walking out and being introduced are still before the bout starts
+        "STATUS_PRE_FIGHT" | "STATUS_FIGHTERS_WALKING" | "STATUS_FIGHTERS_INTRODUCTION" => {
+            Status::Pregame
+        }
+        "STATUS_END_PERIOD"
+        | "STATUS_END_OF_ROUND"
+        | "STATUS_HALFTIME"
+        | "STATUS_DELAYED"
+        | "STATUS_SUSPENDED" => Status::Intermission,
+        "STATUS_POSTPONED" | "STATUS_CANCELED" | "STATUS_ABANDONED" => Status::Invalid,
+        // One odd game shouldn't take down the whole feed, so leave it out
+        _ => {
+            tracing::warn!("Unknown status {input}");
+            Status::Invalid
+        }
+    }
+}
+
+pub fn from_statsapi(abstract_game_state: &str) -> Status {
+    match abstract_game_state {
+        "Preview" => Status::Pregame,
+        "Live" => Status::Active,
+        "Final" => Status::End,
+        _ => Status::Invalid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_espn() {
+        assert_eq!(from_espn("STATUS_END_OF_ROUND"), Status::Intermission);
+        assert_eq!(from_espn("STATUS_FIGHTERS_WALKING"), Status::Pregame);
+        assert_eq!(from_espn("STATUS_SOMETHING_NEW"), Status::Invalid);
     }
 }
@@ -0,0 +1,172 @@
+use chrono::Datelike;
+use serde_json::Value;
+
+use crate::common::competition::{get_broadcasts, get_status_and_time, get_venue};
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array_from_value, get_bool_from_value, get_f64_from_value, get_object_from_value, get_str,
+    get_str_from_value, get_u64_str, get_u64_str_from_value,
+};
+use crate::common::team::{create_team, get_competitor, get_team_map};
+use crate::common::types::game::Status;
+use crate::common::types::{Game, Sport, Team};
+
+fn get_schedule_team(sport: &Sport, competitor: &Value) -> Result<Team, Error> {
+    let team = get_object_from_value(competitor, "team")?;
+    let id = get_u64_str(team, "id")?;
+    if let Some(team) = get_team_map(sport).get(&id) {
+        return Ok(team.clone());
+    }
+    // Schedules leave colors off opponents, so fall back to just the names
+    create_team(competitor).or_else(|_| {
+        Ok(Team {
+            id,
+            location: get_str(team, "location").unwrap_or_default().to_owned(),
+            name: get_str(team, "nickname").unwrap_or_default().to_owned(),
+            display_name: get_str(team, "shortDisplayName")
+                .unwrap_or_default()
+                .to_owned(),
+            abbreviation: get_str(team, "abbreviation")?.to_owned(),
+            ..Default::default()
+        })
+    })
+}
+
+// Scores on a schedule are objects rather than the scoreboard's strings
+fn get_schedule_score(competitor: &Value) -> u64 {
+    competitor
+        .get("score")
+        .and_then(|score| get_f64_from_value(score, "value").ok())
+        .unwrap_or(0.0) as u64
+}
+
+pub fn from_espn_schedule_event(sport: &Sport, event: &Value) -> Result<Option<Game>, Error> {
+    let competition = get_array_from_value(event, "competitions")?
+        .first()
+        .ok_or(format!("Missing competitions in {event}"))?;
+    let (status, time) = match get_status_and_time(competition, None)? {
+        Some(status_and_time) => status_and_time,
+        None => return Ok(None),
+    };
+
+    let competitors = get_array_from_value(competition, "competitors")?;
+    let find_side = |side| {
+        competitors
+            .iter()
+            .find(|c| get_str_from_value(c, "homeAway").is_ok_and(|s| s == side))
+            .ok_or(format!("No {side} team in {event}"))
+    };
+    let (home, away) = (find_side("home")?, find_side("away")?);
+
+    Ok(Some(Game {
+        game_id: get_u64_str_from_value(competition, "id")?,
+        sport: Some(*sport),
+        home_team: Some(get_schedule_team(sport, home)?),
+        away_team: Some(get_schedule_team(sport, away)?),
+        home_team_score: get_schedule_score(home),
+        away_team_score: get_schedule_score(away),
+        status: status.into(),
+        start_time: time.timestamp_nanos(),
+        home_competitor: Some(get_competitor(sport, home)),
+        away_competitor: Some(get_competitor(sport, away)),
+        broadcasts: get_broadcasts(competition),
+        venue: get_venue(competition),
+        neutral_site: get_bool_from_value(competition, "neutralSite").unwrap_or(false),
+        ..Default::default()
+    }))
+}
+
+// NHL seasons span two years and start in the fall, e.g. "20232024"
+pub fn get_nhl_season() -> String {
+    let today = chrono::offset::Utc::now();
+    let start_year = if today.month() >= 9 {
+        today.year()
+    } else {
+        today.year() - 1
+    };
+    format!("{start_year}{}", start_year + 1)
+}
+
+pub fn get_next_game(schedule: Vec<Game>) -> Option<Game> {
+    let now = chrono::offset::Utc::now().timestamp_nanos();
+    schedule
+        .into_iter()
+        .filter(|game| game.status() == Status::Pregame && game.start_time >= now)
+        .min_by_key(|game| game.start_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use crate::common::types::sport::{Level, SportType};
+    use serde_json::json;
+
+    fn schedule_competitor(side: &str, id: &str, abbreviation: &str, score: f64) -> Value {
+        json!({
+            "homeAway": side,
+            "score": {"value": score, "displayValue": score.to_string()},
+            "team": {
+                "id": id,
+                "location": "Somewhere",
+                "nickname": "Club",
+                "shortDisplayName": "Club",
+                "abbreviation": abbreviation,
+            },
+        })
+    }
+
+    #[test]
+    fn test_from_espn_schedule_event() {
+        let sport = new_sport(SportType::Football, Level::Professional);
+        let event = json!({
+            "competitions": [{
+                "id": "401547353",
+                "date": "2023-09-10T17:00Z",
+                "status": {"type": {"name": "STATUS_FINAL"}},
+                "competitors": [
+                    schedule_competitor("away", "90001", "AWY", 17.0),
+                    schedule_competitor("home", "90002", "HOM", 24.0),
+                ],
+            }],
+        });
+        let game = from_espn_schedule_event(&sport, &event).unwrap().unwrap();
+        assert_eq!(game.game_id, 401547353);
+        assert_eq!(game.status(), Status::End);
+        assert_eq!(game.home_team.unwrap().abbreviation, "HOM");
+        assert_eq!(game.away_team.unwrap().abbreviation, "AWY");
+        assert_eq!((game.home_team_score, game.away_team_score), (24, 17));
+
+        let postponed = json!({
+            "competitions": [{
+                "id": "401547354",
+                "date": "2023-09-17T17:00Z",
+                "status": {"type": {"name": "STATUS_POSTPONED"}},
+                "competitors": [],
+            }],
+        });
+        assert!(from_espn_schedule_event(&sport, &postponed)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_next_game() {
+        let hour = 3_600_000_000_000;
+        let now = chrono::offset::Utc::now().timestamp_nanos();
+        let game = |game_id, status: Status, start_time| Game {
+            game_id,
+            status: status.into(),
+            start_time,
+            ..Default::default()
+        };
+        let schedule = vec![
+            game(1, Status::End, now - 48 * hour),
+            game(2, Status::Active, now - hour),
+            game(3, Status::Pregame, now + 72 * hour),
+            game(4, Status::Pregame, now + 24 * hour),
+        ];
+        assert_eq!(get_next_game(schedule).map(|g| g.game_id), Some(4));
+        assert!(get_next_game(vec![game(1, Status::End, now - hour)]).is_none());
+    }
+}
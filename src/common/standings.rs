@@ -0,0 +1,165 @@
+use serde_json::{Map, Value};
+
+use crate::common::data::Error;
+use crate::common::processors::{
+    get_array, get_array_from_value, get_f64_from_value, get_object_from_value, get_str,
+    get_str_from_value, get_u64, get_u64_from_value, get_u64_str,
+};
+use crate::common::types::standings::{Entry, Group};
+
+fn get_stat<'a>(stats: &'a [Value], name: &str) -> Option<&'a Value> {
+    stats
+        .iter()
+        .find(|stat| get_str_from_value(stat, "name").is_ok_and(|n| n == name))
+}
+
+fn get_stat_value(stats: &[Value], name: &str) -> u64 {
+    get_stat(stats, name)
+        .and_then(|stat| get_f64_from_value(stat, "value").ok())
+        .unwrap_or(0.0) as u64
+}
+
+fn get_stat_display(stats: &[Value], name: &str) -> String {
+    get_stat(stats, name)
+        .and_then(|stat| get_str_from_value(stat, "displayValue").ok())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn from_espn_entry(entry: &Value) -> Result<Entry, Error> {
+    let stats = get_array_from_value(entry, "stats")?;
+    let games_behind = match get_stat_display(stats, "gamesBehind").as_str() {
+        "-" => String::new(),
+        games_behind => games_behind.to_owned(),
+    };
+    Ok(Entry {
+        team_id: get_u64_str(get_object_from_value(entry, "team")?, "id")?,
+        wins: get_stat_value(stats, "wins"),
+        losses: get_stat_value(stats, "losses"),
+        ties: get_stat_value(stats, "ties") + get_stat_value(stats, "otLosses"),
+        win_percentage: get_stat(stats, "winPercent")
+            .and_then(|stat| get_f64_from_value(stat, "value").ok())
+            .unwrap_or(0.0),
+        games_behind,
+        points: get_stat_value(stats, "points"),
+        streak: get_stat_display(stats, "streak"),
+        clinch: get_stat_display(stats, "clincher"),
+    })
+}
+
+// Conferences hold either their standings directly or a child group per division
+fn add_espn_groups(group: &Value, out_groups: &mut Vec<Group>) -> Result<(), Error> {
+    if let Ok(standings) = get_object_from_value(group, "standings") {
+        out_groups.push(Group {
+            name: get_str_from_value(group, "name")?.to_owned(),
+            abbreviation: get_str_from_value(group, "abbreviation")
+                .unwrap_or_default()
+                .to_owned(),
+            entries: get_array(standings, "entries")?
+                .iter()
+                .map(from_espn_entry)
+                .collect::<Result<_, _>>()?,
+        });
+    }
+    for child in get_array_from_value(group, "children")
+        .map(|c| c.as_slice())
+        .unwrap_or_default()
+    {
+        add_espn_groups(child, out_groups)?;
+    }
+    Ok(())
+}
+
+pub fn from_espn_standings(json: &Map<String, Value>) -> Result<Vec<Group>, Error> {
+    let mut groups = vec![];
+    for child in get_array(json, "children")? {
+        add_espn_groups(child, &mut groups)?;
+    }
+    Ok(groups)
+}
+
+fn from_statsapi_entry(record: &Value) -> Result<Entry, Error> {
+    let league_record = get_object_from_value(record, "leagueRecord")?;
+    let (wins, losses) = (
+        get_u64(league_record, "wins")?,
+        get_u64(league_record, "losses")?,
+    );
+    let ties = get_u64(league_record, "ot").unwrap_or(0);
+    let games_played = wins + losses + ties;
+    Ok(Entry {
+        team_id: get_u64(get_object_from_value(record, "team")?, "id")?,
+        wins,
+        losses,
+        ties,
+        win_percentage: if games_played > 0 {
+            wins as f64 / games_played as f64
+        } else {
+            0.0
+        },
+        // The NHL ranks on points so there are no games behind
+        games_behind: String::new(),
+        points: get_u64_from_value(record, "points").unwrap_or(0),
+        streak: get_object_from_value(record, "streak")
+            .and_then(|streak| get_str(streak, "streakCode"))
+            .unwrap_or_default()
+            .to_owned(),
+        clinch: get_str_from_value(record, "clinchIndicator")
+            .unwrap_or_default()
+            .to_owned(),
+    })
+}
+
+pub fn from_statsapi_standings(json: &Map<String, Value>) -> Result<Vec<Group>, Error> {
+    get_array(json, "records")?
+        .iter()
+        .map(|record| {
+            let division = get_object_from_value(record, "division")?;
+            Ok(Group {
+                name: get_str(division, "name")?.to_owned(),
+                abbreviation: get_str(division, "abbreviation")
+                    .unwrap_or_default()
+                    .to_owned(),
+                entries: get_array_from_value(record, "teamRecords")?
+                    .iter()
+                    .map(from_statsapi_entry)
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_espn_divisions() {
+        let json = json!({"children": [{
+            "name": "American Football Conference",
+            "abbreviation": "AFC",
+            "children": [{
+                "name": "AFC West",
+                "standings": {"entries": [{
+                    "team": {"id": "12"},
+                    "stats": [
+                        {"name": "wins", "value": 11.0, "displayValue": "11"},
+                        {"name": "losses", "value": 6.0, "displayValue": "6"},
+                        {"name": "winPercent", "value": 0.647, "displayValue": ".647"},
+                        {"name": "gamesBehind", "value": 0.0, "displayValue": "-"},
+                        {"name": "streak", "value": 2.0, "displayValue": "W2"},
+                        {"name": "clincher", "value": 1.0, "displayValue": "z"},
+                    ],
+                }]},
+            }],
+        }]});
+        let groups = from_espn_standings(json.as_object().unwrap()).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].name, "AFC West");
+        let entry = &groups[0].entries[0];
+        assert_eq!((entry.team_id, entry.wins, entry.losses), (12, 11, 6));
+        assert_eq!(entry.games_behind, "");
+        assert_eq!(entry.streak, "W2");
+        assert_eq!(entry.clinch, "z");
+    }
+}
@@ -1,13 +1,18 @@
 use crate::common::data::Error;
-use crate::common::processors::{get_object_from_value, get_str, get_u64, get_u64_str};
+use crate::common::processors::{
+    get_array_from_value, get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_str,
+};
+use crate::common::types::game::Competitor;
 use crate::common::types::sport::{Level, SportType};
 use crate::common::types::{Sport, Team};
 
 use crate::common::color;
+use crate::common::conference::get_conference_for_group;
 use itertools::Itertools;
+use lazy_static::lazy_static;
 use once_cell::sync::Lazy;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub fn get_team_map(sport: &Sport) -> &HashMap<u64, Team> {
     match (sport.sport_type(), sport.level()) {
@@ -20,6 +25,31 @@ pub fn get_team_map(sport: &Sport) -> &HashMap<u64, Team> {
     }
 }
 
+pub fn get_last_name(full_name: &str) -> String {
+    lazy_static! {
+        static ref INVALID_NAMES: HashSet<&'static str> = {
+            let mut s = HashSet::new();
+            s.insert("JR.");
+            s.insert("JR");
+            s.insert("SR.");
+            s.insert("SR");
+            s.insert("II");
+            s.insert("III");
+            s.insert("IV");
+            s.insert("V");
+            s.insert("VI");
+            s
+        };
+    };
+
+    full_name
+        .split(' ')
+        .rev()
+        .find(|s| !INVALID_NAMES.contains(s))
+        .unwrap_or(full_name)
+        .to_owned()
+}
+
 pub fn create_team(competitor: &Value) -> Result<Team, Error> {
     let team = get_object_from_value(competitor, "team")?;
     let id = {
@@ -46,12 +76,65 @@ pub fn create_team(competitor: &Value) -> Result<Team, Error> {
         abbreviation,
         primary_color: Some(color::get_rgb_from_hex(&primary_color)?),
         secondary_color: Some(secondary_color),
+        conference: String::new(),
     };
 
     tracing::info!("Creating unknown team: {:?}", out);
     Ok(out)
 }
 
+pub fn get_competitor(sport: &Sport, competitor: &Value) -> Competitor {
+    let mut out = Competitor::default();
+    for record in get_array_from_value(competitor, "records")
+        .map(|r| r.as_slice())
+        .unwrap_or_default()
+    {
+        let summary = get_str_from_value(record, "summary")
+            .unwrap_or_default()
+            .to_owned();
+        match get_str_from_value(record, "type") {
+            Ok("total") => out.overall_record = summary,
+            Ok("home") => out.home_record = summary,
+            Ok("road") => out.away_record = summary,
+            _ => {}
+        }
+    }
+    // ESPN ranks unranked teams 99
+    out.rank = get_object_from_value(competitor, "curatedRank")
+        .and_then(|rank| get_u64(rank, "current"))
+        .ok()
+        .filter(|rank| *rank <= 25)
+        .unwrap_or(0);
+    out.conference = get_object_from_value(competitor, "team")
+        .and_then(|team| get_u64_str(team, "conferenceId"))
+        .ok()
+        .and_then(|group| get_conference_for_group(sport.sport_type(), group))
+        .map(|conference| conference.slug.to_owned())
+        .unwrap_or_default();
+    out
+}
+
+pub fn get_statsapi_competitor(team: &Value) -> Competitor {
+    let record = get_object_from_value(team, "leagueRecord");
+    let overall_record = match record {
+        Ok(record) => {
+            let (wins, losses) = (
+                get_u64(record, "wins").unwrap_or(0),
+                get_u64(record, "losses").unwrap_or(0),
+            );
+            match get_u64(record, "ot") {
+                Ok(ot) => format!("{wins}-{losses}-{ot}"),
+                Err(_) => format!("{wins}-{losses}"),
+            }
+        }
+        Err(_) => String::new(),
+    };
+    Competitor {
+        overall_record,
+        ..Default::default()
+    }
+}
+
 fn get_display_name(raw: &str) -> String {
     if raw.len() > 11 {
         let mut words = raw.split(' ').collect_vec();
@@ -4,14 +4,24 @@ mod sport;
 use futures::future::join_all;
 use std::collections::{HashMap, HashSet};
 
-pub use common::data::Error;
+pub use common::data::{Error, FetchOptions};
 
-pub use common::proto_helpers::{all_sports, new_sport};
+pub use common::bracket::get_team_path;
+pub use common::proto_helpers::{all_sports, new_golf_tour, new_sport};
 pub use common::team::get_team_map;
-pub use common::types::sport::{Level, SportType};
-pub use common::types::{Game, Sport};
+pub use common::types::sport::{League, Level, SportType};
+pub use common::types::{BoxScore, Bracket, Game, Play, Poll, Sport, Standings};
 
-use common::fetch::{fetch_espn, fetch_statsapi};
+use common::bracket::get_current_round;
+use common::schedule::get_next_game;
+
+use common::fetch::{
+    fetch_espn, fetch_espn_box_score, fetch_espn_bracket, fetch_espn_headlines, fetch_espn_mma,
+    fetch_espn_plays, fetch_espn_polls, fetch_espn_racing, fetch_espn_standings,
+    fetch_espn_team_schedule, fetch_espn_tennis, fetch_statsapi, fetch_statsapi_box_score,
+    fetch_statsapi_bracket, fetch_statsapi_plays, fetch_statsapi_standings,
+    fetch_statsapi_team_schedule,
+};
 
 pub async fn fetch_all() -> Result<HashMap<Sport, Vec<Game>>, Error> {
     fetch_scores(all_sports().into_iter().collect()).await
@@ -27,11 +37,121 @@ pub async fn fetch_scores(sports: HashSet<Sport>) -> Result<HashMap<Sport, Vec<G
     Ok(m)
 }
 pub async fn fetch_sport(sport: Sport) -> (Sport, Result<Vec<Game>, Error>) {
+    fetch_sport_with_options(sport, &FetchOptions::default()).await
+}
+pub async fn fetch_sport_with_options(
+    sport: Sport,
+    options: &FetchOptions,
+) -> (Sport, Result<Vec<Game>, Error>) {
     (
         sport,
         match sport.sport_type() {
             SportType::Hockey => fetch_statsapi(&sport).await,
-            _ => fetch_espn(&sport).await,
+            SportType::Tennis => fetch_espn_tennis(&sport).await,
+            SportType::Racing => fetch_espn_racing(&sport).await,
+            SportType::Mma => fetch_espn_mma(&sport).await,
+            _ => fetch_espn(&sport, options).await,
         },
     )
 }
+
+pub async fn fetch_plays(
+    sport: Sport,
+    game_id: u64,
+    scoring_only: bool,
+) -> Result<Vec<Play>, Error> {
+    match sport.sport_type() {
+        SportType::Hockey => fetch_statsapi_plays(game_id, scoring_only).await,
+        SportType::Football | SportType::Basketball | SportType::Baseball => {
+            fetch_espn_plays(&sport, game_id, scoring_only).await
+        }
+        _ => Err(Error::InvalidSportType(format!(
+            "No play by play for {sport}"
+        ))),
+    }
+}
+
+pub async fn fetch_box_score(sport: Sport, game_id: u64) -> Result<BoxScore, Error> {
+    match sport.sport_type() {
+        SportType::Hockey => fetch_statsapi_box_score(game_id).await,
+        SportType::Football | SportType::Basketball | SportType::Baseball => {
+            fetch_espn_box_score(&sport, game_id).await
+        }
+        _ => Err(Error::InvalidSportType(format!("No box score for {sport}"))),
+    }
+}
+
+pub async fn fetch_standings(sport: Sport) -> Result<Standings, Error> {
+    match sport.sport_type() {
+        SportType::Hockey => fetch_statsapi_standings(&sport).await,
+        SportType::Football | SportType::Basketball | SportType::Baseball => {
+            fetch_espn_standings(&sport).await
+        }
+        _ => Err(Error::InvalidSportType(format!("No standings for {sport}"))),
+    }
+}
+
+pub async fn fetch_team_schedule(sport: Sport, team_id: u64) -> Result<Vec<Game>, Error> {
+    match sport.sport_type() {
+        SportType::Hockey => fetch_statsapi_team_schedule(&sport, team_id).await,
+        SportType::Football | SportType::Basketball | SportType::Baseball => {
+            fetch_espn_team_schedule(&sport, team_id).await
+        }
+        _ => Err(Error::InvalidSportType(format!(
+            "No team schedule for {sport}"
+        ))),
+    }
+}
+
+// The team's next game that hasn't started yet, if there are any left this season
+pub async fn next_game(sport: Sport, team_id: u64) -> Result<Option<Game>, Error> {
+    Ok(get_next_game(fetch_team_schedule(sport, team_id).await?))
+}
+
+// AP, Coaches and (for football) CFP polls, only published for college sports
+pub async fn fetch_polls(sport: Sport) -> Result<Vec<Poll>, Error> {
+    match (sport.sport_type(), sport.level()) {
+        (SportType::Football | SportType::Basketball, Level::Collegiate) => {
+            fetch_espn_polls(&sport).await
+        }
+        _ => Err(Error::InvalidSportType(format!("No polls for {sport}"))),
+    }
+}
+
+pub async fn fetch_bracket(sport: Sport) -> Result<Bracket, Error> {
+    match sport.sport_type() {
+        SportType::Hockey => fetch_statsapi_bracket(&sport).await,
+        SportType::Football | SportType::Basketball | SportType::Baseball => {
+            fetch_espn_bracket(&sport).await
+        }
+        _ => Err(Error::InvalidSportType(format!("No bracket for {sport}"))),
+    }
+}
+
+// Today's games from the bracket's current round, matched on the two teams since series
+// games are only known one at a time
+pub async fn current_round_games(sport: Sport) -> Result<Vec<Game>, Error> {
+    let bracket = fetch_bracket(sport).await?;
+    let round = match get_current_round(&bracket) {
+        Some(round) => round,
+        None => return Ok(vec![]),
+    };
+    let (_, games) = fetch_sport(sport).await;
+    Ok(games?
+        .into_iter()
+        .filter(|game| {
+            let home_id = game.home_team.as_ref().map_or(0, |t| t.id);
+            let away_id = game.away_team.as_ref().map_or(0, |t| t.id);
+            round.matchups.iter().any(|m| {
+                m.game_ids.contains(&game.game_id)
+                    || (m.home_team_id == home_id && m.away_team_id == away_id)
+                    || (m.home_team_id == away_id && m.away_team_id == home_id)
+            })
+        })
+        .collect())
+}
+
+// Every league has a news feed, so unlike the other fetchers there's no sport to reject
+pub async fn fetch_headlines(sport: Sport, max_length: usize) -> Result<Vec<String>, Error> {
+    fetch_espn_headlines(&sport, max_length).await
+}
@@ -1,17 +1,44 @@
-use serde_json::Value;
+use itertools::Itertools;
+use serde_json::{Map, Value};
 
 use crate::common::data::Error;
 
 use crate::common::processors::{
-    get_bool, get_object, get_object_from_value, get_str, get_u64_str,
+    get_array_from_value, get_bool, get_object, get_object_from_value, get_str, get_u64,
+    get_u64_from_value, get_u64_str,
 };
+use crate::common::team::get_last_name;
+use crate::common::types::game::baseball_data::{BaseballPlayer, InningState};
 use crate::common::types::game::{BaseballData, SportData};
 
+fn get_inning_state(short_detail: &str) -> InningState {
+    match short_detail.split(' ').next() {
+        Some("Mid") => InningState::Middle,
+        Some("Bot") => InningState::Bottom,
+        Some("End") => InningState::End,
+        Some("Top") => InningState::Top,
+        _ => InningState::None,
+    }
+}
+
+fn get_player(situation: &Map<String, Value>, name: &'static str) -> Option<BaseballPlayer> {
+    let player = get_object(situation, name).ok()?;
+    let full_name = get_str(get_object(player, "athlete").ok()?, "displayName")
+        .ok()?
+        .to_uppercase();
+    Some(BaseballPlayer {
+        display_name: get_last_name(&full_name),
+        name: full_name,
+        summary: get_str(player, "summary").unwrap_or_default().to_owned(),
+    })
+}
+
 pub fn get_baseball_data(competition: &Value) -> Result<SportData, Error> {
     let situation = get_object_from_value(competition, "situation");
 
     let (mut balls, mut strikes, mut outs) = (0, 0, 0);
     let (mut on_first, mut on_second, mut on_third) = (false, false, false);
+    let (mut pitcher, mut batter, mut pitch_count, mut last_play) = (None, None, 0, String::new());
     if let Ok(situation) = situation {
         balls = get_u64_str(situation, "balls").unwrap_or(0);
         strikes = get_u64_str(situation, "strikes").unwrap_or(0);
@@ -20,10 +47,29 @@ pub fn get_baseball_data(competition: &Value) -> Result<SportData, Error> {
         on_first = get_bool(situation, "onFirst").unwrap_or(false);
         on_second = get_bool(situation, "onSecond").unwrap_or(false);
         on_third = get_bool(situation, "onThird").unwrap_or(false);
+
+        pitcher = get_player(situation, "pitcher");
+        batter = get_player(situation, "batter");
+        pitch_count = get_object(situation, "pitcher")
+            .and_then(|p| get_u64(p, "pitchCount"))
+            .unwrap_or(0);
+        last_play = get_object(situation, "lastPlay")
+            .and_then(|play| get_str(play, "text"))
+            .unwrap_or_default()
+            .to_owned();
     }
 
     let status_object = get_object_from_value(competition, "status")?;
-    let is_inning_top = get_str(get_object(status_object, "type")?, "shortDetail")?.contains("Top");
+    let inning_state =
+        get_inning_state(get_str(get_object(status_object, "type")?, "shortDetail")?);
+    let is_inning_top = inning_state == InningState::Top;
+
+    let competitors = get_array_from_value(competition, "competitors")?;
+    let (home, away) = competitors
+        .iter()
+        .collect_tuple()
+        .ok_or("Failed to unwrap home team and away team")?;
+
     Ok(SportData::BaseballData(BaseballData {
         balls,
         outs,
@@ -32,5 +78,27 @@ pub fn get_baseball_data(competition: &Value) -> Result<SportData, Error> {
         on_first,
         on_second,
         on_third,
+        inning_state: inning_state.into(),
+        pitcher,
+        batter,
+        pitch_count,
+        last_play,
+        home_hits: get_u64_from_value(home, "hits").unwrap_or(0),
+        away_hits: get_u64_from_value(away, "hits").unwrap_or(0),
+        home_errors: get_u64_from_value(home, "errors").unwrap_or(0),
+        away_errors: get_u64_from_value(away, "errors").unwrap_or(0),
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_inning_state() {
+        assert_eq!(get_inning_state("Top 3rd"), InningState::Top);
+        assert_eq!(get_inning_state("Mid 7th"), InningState::Middle);
+        assert_eq!(get_inning_state("Final"), InningState::None);
+        assert_eq!(get_inning_state("Delayed"), InningState::None);
+    }
+}
@@ -1,8 +1,160 @@
-use serde_json::Value;
+use serde_json::{Map, Value};
 
+use crate::common::competition::get_overtime_ordinal;
 use crate::common::data::Error;
-use crate::common::types::game::{BasketballData, SportData};
+use crate::common::processors::{get_object, get_object_from_value, get_str, get_u64};
+use crate::common::types::game::basketball_data::{BasketballTeamData, Bonus};
+use crate::common::types::game::{BasketballData, SportData, Status};
+use crate::common::types::sport::Level;
+use crate::common::types::{Game, Sport};
 
-pub fn get_basketball_data(_competition: &Value) -> Result<SportData, Error> {
-    Ok(SportData::BasketballData(BasketballData {}))
+// A team is in the bonus when the opponent's next common foul sends it to the line.
+// NBA: two shots from a team's 5th foul of a quarter, or its 4th of an overtime period. In the last
+// two minutes of a period a team that isn't over the limit gets one more foul before the penalty.
+// That depends on when the fouls came, so it is only followed when the feed reports foulsToGive.
+// NCAA men: one-and-one from a team's 7th foul of a half, two shots from its 10th.
+fn get_bonus(
+    level: Level,
+    period: u64,
+    opponent_fouls: u64,
+    opponent_fouls_to_give: Option<u64>,
+) -> Bonus {
+    match level {
+        Level::Professional => {
+            let is_penalty = match opponent_fouls_to_give {
+                Some(fouls_to_give) => fouls_to_give == 0,
+                None => opponent_fouls >= if period > 4 { 3 } else { 4 },
+            };
+            if is_penalty {
+                Bonus::Bonus
+            } else {
+                Bonus::None
+            }
+        }
+        Level::Collegiate => match opponent_fouls {
+            9.. => Bonus::DoubleBonus,
+            6.. => Bonus::Bonus,
+            _ => Bonus::None,
+        },
+    }
+}
+
+pub fn get_basketball_ordinal(sport: &Sport, period: u64) -> String {
+    let regulation_periods = match sport.level() {
+        Level::Professional => 4,
+        Level::Collegiate => 2,
+    };
+    get_overtime_ordinal(period, regulation_periods)
+}
+
+fn get_team_fouls(situation: &Map<String, Value>, fouls_key: &'static str) -> u64 {
+    get_object(situation, fouls_key)
+        .and_then(|f| get_u64(f, "teamFouls"))
+        .unwrap_or(0)
+}
+
+fn get_team_data(
+    situation: &Map<String, Value>,
+    fouls_key: &'static str,
+    timeouts_key: &'static str,
+    opponent_fouls_key: &'static str,
+    level: Level,
+    period: u64,
+) -> BasketballTeamData {
+    let bonus_state = get_object(situation, fouls_key).and_then(|f| get_str(f, "bonusState"));
+    let bonus = match bonus_state {
+        Ok("DOUBLE_BONUS") => Bonus::DoubleBonus,
+        Ok("BONUS") => Bonus::Bonus,
+        Ok(_) => Bonus::None,
+        // Not every feed reports the bonus, so work it out from the opponent's fouls
+        Err(_) => get_bonus(
+            level,
+            period,
+            get_team_fouls(situation, opponent_fouls_key),
+            get_object(situation, opponent_fouls_key)
+                .and_then(|f| get_u64(f, "foulsToGive"))
+                .ok(),
+        ),
+    };
+    BasketballTeamData {
+        fouls: get_team_fouls(situation, fouls_key),
+        timeouts_remaining: get_u64(situation, timeouts_key).unwrap_or(0),
+        bonus: bonus.into(),
+    }
+}
+
+pub fn get_basketball_data(competition: &Value, game: &Game) -> Result<SportData, Error> {
+    let situation = get_object_from_value(competition, "situation");
+    let status_object = get_object_from_value(competition, "status")?;
+
+    let time_remaining = if game.status() != Status::Active {
+        ""
+    } else {
+        get_str(status_object, "displayClock").unwrap_or_default()
+    }
+    .to_owned();
+
+    if let Ok(situation) = situation {
+        let level = game.sport.unwrap_or_default().level();
+        let last_play = get_object(situation, "lastPlay")
+            .and_then(|play| get_str(play, "text"))
+            .unwrap_or_default()
+            .to_owned();
+
+        Ok(SportData::BasketballData(BasketballData {
+            time_remaining,
+            home_team: Some(get_team_data(
+                situation,
+                "homeFouls",
+                "homeTimeouts",
+                "awayFouls",
+                level,
+                game.period,
+            )),
+            away_team: Some(get_team_data(
+                situation,
+                "awayFouls",
+                "awayTimeouts",
+                "homeFouls",
+                level,
+                game.period,
+            )),
+            last_play,
+        }))
+    } else {
+        Ok(SportData::BasketballData(BasketballData {
+            time_remaining,
+            home_team: None,
+            away_team: None,
+            last_play: "".to_owned(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_bonus() {
+        let nba = |period, fouls| get_bonus(Level::Professional, period, fouls, None);
+        assert_eq!(nba(2, 3), Bonus::None);
+        assert_eq!(nba(2, 4), Bonus::Bonus);
+        assert_eq!(nba(2, 5), Bonus::Bonus);
+        assert_eq!(nba(2, 6), Bonus::Bonus);
+        // Overtime periods allow one foul fewer
+        assert_eq!(nba(5, 2), Bonus::None);
+        assert_eq!(nba(5, 3), Bonus::Bonus);
+        // The feed's fouls to give covers the last two minutes
+        assert_eq!(get_bonus(Level::Professional, 4, 2, Some(0)), Bonus::Bonus);
+        assert_eq!(get_bonus(Level::Professional, 4, 4, Some(1)), Bonus::None);
+
+        let ncaa = |fouls| get_bonus(Level::Collegiate, 2, fouls, None);
+        assert_eq!(ncaa(4), Bonus::None);
+        assert_eq!(ncaa(5), Bonus::None);
+        assert_eq!(ncaa(6), Bonus::Bonus);
+        assert_eq!(ncaa(8), Bonus::Bonus);
+        assert_eq!(ncaa(9), Bonus::DoubleBonus);
+        assert_eq!(ncaa(10), Bonus::DoubleBonus);
+    }
 }
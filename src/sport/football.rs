@@ -1,12 +1,35 @@
-use serde_json::Value;
+use ordinal::Ordinal;
+use serde_json::{Map, Value};
 
 use crate::common::data::Error;
 
-use crate::common::processors::{get_object_from_value, get_str, get_u64_str};
-use crate::common::types::game::football_data::Possession;
+use crate::common::processors::{
+    get_bool, get_object, get_object_from_value, get_str, get_u64, get_u64_str,
+};
+use crate::common::types::game::football_data::{Possession, ScoringPlay};
 use crate::common::types::game::{FootballData, SportData, Status};
 use crate::common::types::Game;
 
+pub fn get_scoring_play(play: &Map<String, Value>) -> ScoringPlay {
+    let play_type = get_object(play, "type")
+        .and_then(|t| get_str(t, "text"))
+        .unwrap_or_default()
+        .to_lowercase();
+    if play_type.contains("two-point") || play_type.contains("two point") {
+        ScoringPlay::TwoPointConversion
+    } else if play_type.contains("extra point") {
+        ScoringPlay::ExtraPoint
+    } else if play_type.contains("touchdown") {
+        ScoringPlay::Touchdown
+    } else if play_type.contains("field goal good") || play_type == "field goal" {
+        ScoringPlay::FieldGoal
+    } else if play_type.contains("safety") {
+        ScoringPlay::Safety
+    } else {
+        ScoringPlay::NoScore
+    }
+}
+
 pub fn get_football_data(competition: &Value, game: &Game) -> Result<SportData, Error> {
     let situation = get_object_from_value(competition, "situation");
     let status_object = get_object_from_value(competition, "status")?;
@@ -23,9 +46,16 @@ pub fn get_football_data(competition: &Value, game: &Game) -> Result<SportData,
             .unwrap_or_default()
             .to_owned();
 
-        let down_string = {
-            let s = get_str(situation, "shortDownDistanceText").unwrap_or_default();
-            s.replace('&', "+")
+        let down = get_u64(situation, "down").unwrap_or(0);
+        let distance = get_u64(situation, "distance").unwrap_or(0);
+        let yard_line = get_u64(situation, "yardLine").unwrap_or(0);
+        let is_goal_to_go = get_str(situation, "shortDownDistanceText")
+            .unwrap_or_default()
+            .contains("Goal");
+        let down_string = match (down, is_goal_to_go) {
+            (0, _) => String::new(),
+            (_, true) => format!("{} + Goal", Ordinal(down)),
+            (_, false) => format!("{} + {distance}", Ordinal(down)),
         };
 
         let possession = if let Ok(possessing_team_id) = get_u64_str(situation, "possession") {
@@ -44,11 +74,26 @@ pub fn get_football_data(competition: &Value, game: &Game) -> Result<SportData,
             Possession::None
         };
 
+        let last_play = get_object(situation, "lastPlay")
+            .and_then(|play| get_str(play, "text"))
+            .unwrap_or_default()
+            .to_owned();
+
         Ok(SportData::FootballData(FootballData {
             time_remaining,
             ball_position,
             down_string,
             possession: possession.into(),
+            down,
+            distance,
+            yard_line,
+            is_goal_to_go,
+            is_red_zone: get_bool(situation, "isRedZone").unwrap_or(false),
+            home_timeouts: get_u64(situation, "homeTimeouts").unwrap_or(0),
+            away_timeouts: get_u64(situation, "awayTimeouts").unwrap_or(0),
+            last_play,
+            // The scoreboard has no list of scoring plays, the summary fills this in
+            last_scoring_play: ScoringPlay::NoScore.into(),
         }))
     } else {
         Ok(SportData::FootballData(FootballData {
@@ -56,6 +101,31 @@ pub fn get_football_data(competition: &Value, game: &Game) -> Result<SportData,
             ball_position: "".to_owned(),
             down_string: "".to_owned(),
             possession: Possession::None.into(),
+            last_scoring_play: ScoringPlay::NoScore.into(),
+            ..Default::default()
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_scoring_play() {
+        // Shaped like the summary's scoringPlays entries
+        let scoring_play = |text: &str| {
+            let play = json!({"type": {"text": text}, "text": "", "homeScore": 7, "awayScore": 3});
+            get_scoring_play(play.as_object().unwrap())
+        };
+        assert_eq!(scoring_play("Passing Touchdown"), ScoringPlay::Touchdown);
+        assert_eq!(scoring_play("Field Goal Good"), ScoringPlay::FieldGoal);
+        assert_eq!(scoring_play("Safety"), ScoringPlay::Safety);
+        assert_eq!(
+            scoring_play("Two-Point Conversion"),
+            ScoringPlay::TwoPointConversion
+        );
+        assert_eq!(scoring_play("Field Goal Missed"), ScoringPlay::NoScore);
+    }
+}
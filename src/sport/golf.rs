@@ -5,17 +5,81 @@ use regex::Regex;
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 
-use crate::common::data::Error;
+use crate::common::competition::{get_broadcasts, get_venue};
+use crate::common::data::{Error, FetchOptions};
 use crate::common::proto_helpers::from_espn;
+use crate::common::team::get_last_name;
 use crate::common::types::game::golf_data::GolfPlayer;
 use crate::common::types::game::{GolfData, SportData, Status};
-use crate::common::types::Game;
+use crate::common::types::{Game, Sport};
 
 use crate::common::processors::{
-    get_array_from_value, get_object, get_object_from_value, get_str, get_str_from_value, get_u64,
-    get_u64_str, get_u64_str_from_value,
+    get_array_from_value, get_bool, get_f64_from_value, get_object, get_object_from_value, get_str,
+    get_str_from_value, get_u64, get_u64_str, get_u64_str_from_value,
 };
-use crate::{new_sport, Level, SportType};
+
+// Fills in how far through their round a player is, shared by individual and team events
+fn add_progress(player: &mut GolfPlayer, competitor: &Value) -> Result<(), Error> {
+    let status = get_object_from_value(competitor, "status")?;
+    let thru = get_u64(status, "thru").unwrap_or(0);
+    player.thru = match thru {
+        0 => String::new(),
+        18 => "F".to_owned(),
+        _ => thru.to_string(),
+    };
+    if thru == 0 {
+        if let Ok(tee_time) = get_str(status, "teeTime") {
+            let time = NaiveDateTime::parse_from_str(tee_time, "%Y-%m-%dT%H:%MZ")?;
+            let time: DateTime<chrono::Utc> = DateTime::from_utc(time, chrono::Utc);
+            player.tee_time = time.timestamp_nanos();
+        }
+    }
+    player.is_tied = get_object(status, "position")
+        .and_then(|position| get_bool(position, "isTie"))
+        .unwrap_or(false);
+    player.missed_cut = get_object(status, "type")
+        .and_then(|t| get_str(t, "name"))
+        .is_ok_and(|name| name == "STATUS_CUT");
+
+    let period = get_u64(status, "period").unwrap_or(1);
+    player.today = get_array_from_value(competitor, "linescores")
+        .ok()
+        .and_then(|rounds| rounds.get(period.saturating_sub(1) as usize))
+        .and_then(|round| get_str_from_value(round, "displayValue").ok())
+        .unwrap_or_default()
+        .to_owned();
+    Ok(())
+}
+
+// Takes the top of the leaderboard, plus any followed golfers further down
+fn select_players(mut candidates: Vec<GolfPlayer>, options: &FetchOptions) -> Vec<GolfPlayer> {
+    // Teams out of the event have no position, so they go after everyone still playing
+    candidates.sort_by_key(|c| (c.missed_cut || c.withdrawn, c.position));
+    for candidate in candidates.iter_mut() {
+        candidate.is_followed = options
+            .followed_golfers
+            .iter()
+            .any(|followed| followed.eq_ignore_ascii_case(&candidate.name));
+    }
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter(|(i, c)| *i < options.golf_leaderboard_depth || c.is_followed)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+fn get_cut_line(event: &Value) -> String {
+    match event
+        .get("tournament")
+        .and_then(|tournament| get_f64_from_value(tournament, "cutScore").ok())
+    {
+        Some(score) if score > 0.0 => format!("+{score}"),
+        Some(score) if score < 0.0 => format!("{score}"),
+        Some(_) => "E".to_owned(),
+        None => String::new(),
+    }
+}
 
 pub fn from_teamstroke(competitor: &Value) -> Result<GolfPlayer, Error> {
     let stats = get_array_from_value(competitor, "statistics")?;
@@ -28,39 +92,148 @@ pub fn from_teamstroke(competitor: &Value) -> Result<GolfPlayer, Error> {
     let mut names = vec![];
     let roster = get_array_from_value(competitor, "roster")?;
     for player in roster {
-        let last_name = &get_str(get_object_from_value(player, "athlete")?, "lastName")?[0..5];
-        names.push(last_name);
+        let last_name = get_str(get_object_from_value(player, "athlete")?, "lastName")?;
+        names.push(get_partner_name(last_name));
     }
-    let display_name = names.iter().join("/").to_uppercase();
+    let display_name = names.iter().join("/");
     let position = get_u64_str(
         get_object(get_object_from_value(competitor, "status")?, "position")?,
         "id",
     )?;
-    Ok(GolfPlayer {
+    let mut player = GolfPlayer {
         name: display_name.clone(),
         display_name,
         score,
         position,
-    })
+        ..Default::default()
+    };
+    add_progress(&mut player, competitor)?;
+    Ok(player)
+}
+// Team events only have room for the first five letters of each partner's last name
+fn get_partner_name(name: &str) -> String {
+    get_last_name(&name.trim().to_uppercase())
+        .chars()
+        .take(5)
+        .collect()
+}
+
+struct RawDataLine {
+    position: Option<u64>,
+    is_tied: bool,
+    missed_cut: bool,
+    withdrawn: bool,
+    partners: (String, String),
+    score: String,
+    thru: String,
 }
-pub fn from_raw_data(line: &str, position: usize) -> Option<GolfPlayer> {
+
+// Splits a single rawData leaderboard line, e.g. "T3  Nick Taylor/Adam Hadwin  -18  F"
+fn tokenize_raw_data_line(line: &str) -> Option<RawDataLine> {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r#".*\s([a-zA-z ]+)/([a-zA-z ]+)\s*([^\s]+)+"#).unwrap();
+        static ref POSITION: Regex = Regex::new(r"^T?(\d+)(T?)$").unwrap();
+        static ref SCORE: Regex = Regex::new(r"^([+-]\d+|E|EVEN)$").unwrap();
+        static ref THRU: Regex = Regex::new(r"^(F|\d{1,2})\*?$").unwrap();
     }
-    if let Some(cap) = RE.captures_iter(line).next() {
-        let player_a = &cap[0];
-        let player_b = &cap[1];
-        let score = cap[2].to_owned();
-        let display_name = format!("{}/{}", &player_a[..5], &player_b[..5]);
-        Some(GolfPlayer {
+    let mut tokens = line.split_whitespace().peekable();
+
+    let (position, is_tied) = match tokens.peek().copied().and_then(|t| POSITION.captures(t)) {
+        Some(cap) => {
+            tokens.next();
+            (cap[1].parse::<u64>().ok(), cap[0].contains('T'))
+        }
+        None => (None, false),
+    };
+    // Teams that are out of the event have a status where the position would be
+    let (missed_cut, withdrawn) = match tokens.peek().copied() {
+        Some("CUT" | "MC" | "MDF" | "MDC") if position.is_none() => {
+            tokens.next();
+            (true, false)
+        }
+        Some("WD" | "DQ") if position.is_none() => {
+            tokens.next();
+            (false, true)
+        }
+        _ => (false, false),
+    };
+
+    let mut names = vec![];
+    let mut score = None;
+    for token in tokens.by_ref() {
+        if SCORE.is_match(token) && names.iter().any(|n: &&str| n.contains('/')) {
+            score = Some(if token == "EVEN" { "E" } else { token });
+            break;
+        }
+        names.push(token);
+    }
+    // A withdrawn team may not have a score at all
+    let score = match score {
+        Some(score) => score.to_owned(),
+        None if missed_cut || withdrawn => String::new(),
+        None => return None,
+    };
+    let thru = tokens
+        .next()
+        .and_then(|t| THRU.captures(t))
+        .map(|cap| cap[1].to_owned())
+        .map(|thru| if thru == "18" { "F".to_owned() } else { thru })
+        .unwrap_or_default();
+
+    let names = names.join(" ");
+    let (player_a, player_b) = names.split_once('/')?;
+    if player_a.trim().is_empty() || player_b.trim().is_empty() {
+        return None;
+    }
+
+    Some(RawDataLine {
+        position,
+        is_tied,
+        missed_cut,
+        withdrawn,
+        partners: (get_partner_name(player_a), get_partner_name(player_b)),
+        score,
+        thru,
+    })
+}
+
+// Parses the leaderboard ESPN publishes as plain text for team events like the Zurich Classic.
+// Teams sharing a position may only list it on the first line of the tie. Teams that missed the
+// cut or withdrew have no position, and a line without a status that follows them is one more
+// team in the same state rather than part of a tie.
+pub fn from_raw_data(raw_data: &str) -> Vec<GolfPlayer> {
+    let mut players: Vec<GolfPlayer> = vec![];
+    for line in raw_data.lines() {
+        let Some(parsed) = tokenize_raw_data_line(line) else {
+            continue;
+        };
+        let out_of_event = parsed.missed_cut || parsed.withdrawn;
+        let (position, is_tied, missed_cut, withdrawn) = match (parsed.position, players.last_mut())
+        {
+            _ if out_of_event => (0, false, parsed.missed_cut, parsed.withdrawn),
+            (Some(position), _) => (position, parsed.is_tied, false, false),
+            (None, Some(previous)) if previous.missed_cut || previous.withdrawn => {
+                (0, false, previous.missed_cut, previous.withdrawn)
+            }
+            (None, Some(previous)) => {
+                previous.is_tied = true;
+                (previous.position, true, false, false)
+            }
+            (None, None) => (1, false, false, false),
+        };
+        let display_name = format!("{}/{}", parsed.partners.0, parsed.partners.1);
+        players.push(GolfPlayer {
             name: display_name.clone(),
             display_name,
-            score,
-            position: position as u64,
-        })
-    } else {
-        None
+            score: parsed.score,
+            position,
+            thru: parsed.thru,
+            is_tied,
+            missed_cut,
+            withdrawn,
+            ..Default::default()
+        });
     }
+    players
 }
 
 pub fn from_competitor(competitor: &Value) -> Result<GolfPlayer, Error> {
@@ -72,43 +245,96 @@ pub fn from_competitor(competitor: &Value) -> Result<GolfPlayer, Error> {
     }
     .to_owned();
 
-    lazy_static! {
-        static ref INVALID_NAMES: HashSet<&'static str> = {
-            let mut s = HashSet::new();
-            s.insert("JR.");
-            s.insert("JR");
-            s.insert("SR.");
-            s.insert("SR");
-            s.insert("II");
-            s.insert("III");
-            s.insert("IV");
-            s.insert("V");
-            s.insert("VI");
-            s
-        };
-    };
-
     let full_name =
         get_str(get_object_from_value(competitor, "athlete")?, "displayName")?.to_uppercase();
-    let last_name = full_name
-        .split(' ')
-        .rev()
-        .find(|s| !INVALID_NAMES.contains(s))
-        .unwrap()
-        .to_owned();
+    let last_name = get_last_name(&full_name);
     let position = get_u64_str(
         get_object(get_object_from_value(competitor, "status")?, "position")?,
         "id",
     )?;
-    Ok(GolfPlayer {
+    let mut player = GolfPlayer {
         name: full_name,
         display_name: last_name,
         position,
         score,
-    })
+        ..Default::default()
+    };
+    add_progress(&mut player, competitor)?;
+    Ok(player)
+}
+
+lazy_static! {
+    static ref NAME_MAP: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert("SHRINERS CHILDREN'S OPEN", "SHRINERS OPEN");
+        m.insert("BUTTERFIELD BERMUDA CHAMPIONSHIP", "BERMUDA CHAMP");
+        m.insert(
+            "WORLD WIDE TECHNOLOGY CHAMPIONSHIP AT MAYAKOBA",
+            "WWT CHAMP",
+        );
+        m.insert("FARMERS INSURANCE OPEN", "FARMERS OPEN");
+        m.insert("SONY OPEN IN HAWAII", "SONY OPEN");
+        m.insert("AT&T PEBBLE BEACH PRO-AM", "PEBBLE BEACH");
+        m.insert("WASTE MANAGEMENT PHOENIX OPEN", "WM PHOENIX");
+        m.insert("CORALES PUNTACANA CHAMPIONSHIP", "PUTACANA CHAMP");
+        m.insert("VALERO TEXAS OPEN", "VALERO OPEN");
+        m.insert("RBC CANADIAN OPEN", "RBC CANADIAN");
+        m.insert("GENESIS SCOTTISH OPEN", "SCOTTISH OPEN");
+        m.insert("THE CJ CUP IN SOUTH CAROLINA", "CJ CUP");
+        m.insert("CADENCE BANK HOUSTON OPEN", "HOUSTON OPEN");
+        m.insert("KPMG WOMEN'S PGA CHAMPIONSHIP", "WOMEN'S PGA");
+        m.insert("AMUNDI EVIAN CHAMPIONSHIP", "EVIAN");
+        m.insert("HILTON GRAND VACATIONS TOURNAMENT OF CHAMPIONS", "LPGA TOC");
+        m.insert("ABU DHABI HSBC CHAMPIONSHIP", "ABU DHABI");
+        m.insert("HERO DUBAI DESERT CLASSIC", "DUBAI DESERT");
+        m.insert("ALFRED DUNHILL LINKS CHAMPIONSHIP", "DUNHILL LINKS");
+        m.insert("CHARLES SCHWAB CUP CHAMPIONSHIP", "SCHWAB CUP");
+        m.insert("MITSUBISHI ELECTRIC CHAMPIONSHIP AT HUALALAI", "HUALALAI");
+        m
+    };
+    static ref DUMB_WORDS: HashSet<&'static str> = {
+        let mut s = HashSet::new();
+        s.insert("TOURNAMENT");
+        s.insert("CHAMPIONSHIP");
+        s.insert("CHALLENGE");
+        s.insert("CLASSIC");
+        s.insert("INVITATIONAL");
+        s
+    };
 }
 
-pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
+fn remove_dumb_words(name: &str) -> String {
+    // LIV puts "LIV Golf" in front of every event, the LIV is enough. Golf on its own is kept.
+    name.replace("LIV GOLF", "LIV")
+        .split_whitespace()
+        .filter(|word| {
+            !DUMB_WORDS.contains(*word) // TODO remove numbers
+        })
+        .join(" ")
+}
+
+pub fn shorten_event_name(name: &str) -> String {
+    let mut name = name.to_uppercase();
+    if let Some(new_name) = NAME_MAP.get(&name as &str) {
+        name = new_name.to_string()
+    }
+    remove_dumb_words(&name)
+}
+
+// Same rules as event names, but the event can appear anywhere in a headline
+pub fn shorten_headline(headline: &str) -> String {
+    let mut headline = headline.to_uppercase();
+    for (name, short_name) in NAME_MAP.iter() {
+        headline = headline.replace(name, short_name);
+    }
+    remove_dumb_words(&headline)
+}
+
+pub fn process_golf(
+    sport: &Sport,
+    events: &Vec<Value>,
+    options: &FetchOptions,
+) -> Result<Vec<Game>, Error> {
     let mut out_games = Vec::new();
 
     for event in events {
@@ -133,7 +359,7 @@ pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
             if let Ok(tee_time) = get_str(player_status, "teeTime") {
                 let time = NaiveDateTime::parse_from_str(tee_time, "%Y-%m-%dT%H:%MZ")?;
                 let time: DateTime<chrono::Utc> = DateTime::from_utc(time, chrono::Utc);
-                if earliest_tee_time.map_or(true, |earliest| time < earliest) {
+                if earliest_tee_time.is_none_or(|earliest| time < earliest) {
                     earliest_tee_time = Some(time);
                 }
             }
@@ -168,19 +394,14 @@ pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
             status = Status::End;
         }
 
-        let top_5: Vec<GolfPlayer>;
+        let players: Vec<GolfPlayer>;
         if scoring_system == "Teamstroke" {
             if let Ok(raw_data) = get_str_from_value(competition, "rawData") {
                 if status == Status::Active && raw_data.contains("COMPLETE") {
                     status = Status::End;
                 }
 
-                top_5 = raw_data
-                    .split('\n')
-                    .enumerate()
-                    .filter_map(|(position, line)| from_raw_data(line, position))
-                    .take(5)
-                    .collect();
+                players = select_players(from_raw_data(raw_data), options);
             } else {
                 // No raw data
                 let competitors = get_array_from_value(competition, "competitors")?;
@@ -188,8 +409,7 @@ pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
                 for competitor in competitors {
                     candidates.push(from_teamstroke(competitor)?)
                 }
-                candidates.sort_by(|a, b| a.position.cmp(&b.position));
-                top_5 = candidates.into_iter().take(5).collect();
+                players = select_players(candidates, options);
             }
         } else {
             let competitors = get_array_from_value(competition, "competitors")?;
@@ -197,59 +417,14 @@ pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
             for competitor in competitors {
                 candidates.push(from_competitor(competitor)?)
             }
-            candidates.sort_by(|a, b| a.position.cmp(&b.position));
-            top_5 = candidates.into_iter().take(5).collect();
+            players = select_players(candidates, options);
         }
 
-        let mut name = get_str_from_value(event, "shortName")?.to_uppercase();
-        lazy_static! {
-            static ref NAME_MAP: HashMap<&'static str, &'static str> = {
-                let mut m = HashMap::new();
-                m.insert("SHRINERS CHILDREN'S OPEN", "SHRINERS OPEN");
-                m.insert("BUTTERFIELD BERMUDA CHAMPIONSHIP", "BERMUDA CHAMP");
-                m.insert(
-                    "WORLD WIDE TECHNOLOGY CHAMPIONSHIP AT MAYAKOBA",
-                    "WWT CHAMP",
-                );
-                m.insert("FARMERS INSURANCE OPEN", "FARMERS OPEN");
-                m.insert("SONY OPEN IN HAWAII", "SONY OPEN");
-                m.insert("AT&T PEBBLE BEACH PRO-AM", "PEBBLE BEACH");
-                m.insert("WASTE MANAGEMENT PHOENIX OPEN", "WM PHOENIX");
-                m.insert("CORALES PUNTACANA CHAMPIONSHIP", "PUTACANA CHAMP");
-                m.insert("VALERO TEXAS OPEN", "VALERO OPEN");
-                m.insert("RBC CANADIAN OPEN", "RBC CANADIAN");
-                m.insert("GENESIS SCOTTISH OPEN", "SCOTTISH OPEN");
-                m.insert("THE CJ CUP IN SOUTH CAROLINA", "CJ CUP");
-                m.insert("CADENCE BANK HOUSTON OPEN", "HOUSTON OPEN");
-                m
-            };
-        };
-        if let Some(new_name) = NAME_MAP.get(&name as &str) {
-            name = new_name.to_string()
-        }
-
-        lazy_static! {
-            static ref DUMB_WORDS: HashSet<&'static str> = {
-                let mut s = HashSet::new();
-                s.insert("TOURNAMENT");
-                s.insert("CHAMPIONSHIP");
-                s.insert("CHALLENGE");
-                s.insert("CLASSIC");
-                s.insert("INVITATIONAL");
-                s
-            };
-        };
-
-        let name = name
-            .split(' ')
-            .filter(|word| {
-                !DUMB_WORDS.contains(*word) // TODO remove numbers
-            })
-            .join(" ");
+        let name = shorten_event_name(get_str_from_value(event, "shortName")?);
 
         out_games.push(Game {
             game_id,
-            sport: Some(new_sport(SportType::Golf, Level::Professional)),
+            sport: Some(*sport),
             home_team: None,
             away_team: None,
             home_team_score: 0,
@@ -260,9 +435,165 @@ pub fn process_golf(events: &Vec<Value>) -> Result<Vec<Game>, Error> {
             start_time: time.timestamp_nanos(),
             sport_data: Some(SportData::GolfData(GolfData {
                 event_name: name,
-                players: top_5,
+                players,
+                cut_line: get_cut_line(event),
             })),
+            broadcasts: get_broadcasts(competition),
+            venue: get_venue(competition).or_else(|| get_venue(event)),
+            ..Default::default()
         })
     }
     Ok(out_games)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(players: &[GolfPlayer]) -> Vec<(u64, bool, &str, &str, &str)> {
+        players
+            .iter()
+            .map(|p| {
+                (
+                    p.position,
+                    p.is_tied,
+                    p.display_name.as_str(),
+                    p.score.as_str(),
+                    p.thru.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_raw_data_full_names() {
+        let raw_data = "Zurich Classic of New Orleans\n\
+                        Round 4 - Play Complete\n\
+                        POS TEAM TO PAR THRU\n\
+                        1 Nick Taylor/Adam Hadwin -20 F\n\
+                        T2 Rory McIlroy/Shane Lowry -19 F\n\
+                        T2 Harris English/Tom Hoge -19 F\n\
+                        4 Byeong Hun An/Seung-Yul Noh -17 F\n";
+        let players = from_raw_data(raw_data);
+        assert_eq!(
+            summary(&players),
+            vec![
+                (1, false, "TAYLO/HADWI", "-20", "F"),
+                (2, true, "MCILR/LOWRY", "-19", "F"),
+                (2, true, "ENGLI/HOGE", "-19", "F"),
+                (4, false, "AN/NOH", "-17", "F"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_data_ties_listed_once() {
+        let raw_data = "T5  Smith/Jones  -10  12\n\
+                        \x20   Cink/Kuchar  -10  9*\n\
+                        7   Lee/Kim  E  18\n\
+                        2T  Hovland/Ruud  EVEN";
+        let players = from_raw_data(raw_data);
+        assert_eq!(
+            summary(&players),
+            vec![
+                (5, true, "SMITH/JONES", "-10", "12"),
+                (5, true, "CINK/KUCHA", "-10", "9"),
+                (7, false, "LEE/KIM", "E", "F"),
+                (2, true, "HOVLA/RUUD", "E", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_data_unusual_names() {
+        let raw_data = "1 Nicolai Højgaard/Rasmus Højgaard -15 F\n\
+                        2 Cam Davis / Adam Scott -12 F\n\
+                        3 Davis Love III/Dru Love +3 F\n";
+        let players = from_raw_data(raw_data);
+        assert_eq!(
+            summary(&players),
+            vec![
+                (1, false, "HØJGA/HØJGA", "-15", "F"),
+                (2, false, "DAVIS/SCOTT", "-12", "F"),
+                (3, false, "LOVE/LOVE", "+3", "F"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_data_cut_and_withdrawn() {
+        let raw_data = "POS TEAM TO PAR THRU\n\
+                        T33 Nick Taylor/Adam Hadwin -8 F\n\
+                        \x20   Sam Burns/Billy Horschel -8 F\n\
+                        CUT Nick Hardy/Davis Riley -4\n\
+                        \x20   Kevin Kisner/Chris Kirk -3\n\
+                        MDF Aaron Rai/Matt Wallace -6 F\n\
+                        WD Justin Thomas/Jordan Spieth +2 9\n\
+                        DQ Erik van Rooyen/Thriston Lawrence\n";
+        let players = from_raw_data(raw_data);
+        assert_eq!(
+            summary(&players),
+            vec![
+                (33, true, "TAYLO/HADWI", "-8", "F"),
+                (33, true, "BURNS/HORSC", "-8", "F"),
+                (0, false, "HARDY/RILEY", "-4", ""),
+                (0, false, "KISNE/KIRK", "-3", ""),
+                (0, false, "RAI/WALLA", "-6", "F"),
+                (0, false, "THOMA/SPIET", "+2", "9"),
+                (0, false, "ROOYE/LAWRE", "", ""),
+            ]
+        );
+        let states = players
+            .iter()
+            .map(|p| (p.missed_cut, p.withdrawn))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            states,
+            vec![
+                (false, false),
+                (false, false),
+                (true, false),
+                (true, false),
+                (true, false),
+                (false, true),
+                (false, true),
+            ]
+        );
+
+        // Out of the event teams go after everyone still playing, whatever the order ESPN lists
+        let options = FetchOptions {
+            golf_leaderboard_depth: 3,
+            ..Default::default()
+        };
+        let selected = select_players(players, &options);
+        assert_eq!(
+            summary(&selected),
+            vec![
+                (33, true, "TAYLO/HADWI", "-8", "F"),
+                (33, true, "BURNS/HORSC", "-8", "F"),
+                (0, false, "HARDY/RILEY", "-4", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shorten_names() {
+        assert_eq!(shorten_event_name("LIV Golf Jeddah"), "LIV JEDDAH");
+        assert_eq!(shorten_event_name("Genesis Scottish Open"), "SCOTTISH OPEN");
+        assert_eq!(
+            shorten_headline("LIV Golf Andalucia moves to Valderrama"),
+            "LIV ANDALUCIA MOVES TO VALDERRAMA"
+        );
+        assert_eq!(
+            shorten_headline("Olympic golf field set for Paris"),
+            "OLYMPIC GOLF FIELD SET FOR PARIS"
+        );
+    }
+
+    #[test]
+    fn test_raw_data_without_teams() {
+        assert!(from_raw_data("").is_empty());
+        assert!(from_raw_data("Round 2 - Suspended\nPOS TEAM SCORE\n").is_empty());
+        assert!(from_raw_data("1 Scheffler -10 F").is_empty());
+    }
+}
@@ -1,10 +1,33 @@
+use serde_json::{Map, Value};
+
 use crate::common::data::Error;
-use crate::common::processors::{get_bool, get_object, get_str, get_u64};
-use crate::common::types::game::hockey_data::HockeyTeamData;
-use crate::common::types::game::Status;
+use crate::common::processors::{
+    get_array, get_bool, get_object, get_object_from_value, get_str, get_str_from_value, get_u64,
+    get_u64_from_value,
+};
+use crate::common::types::game::hockey_data::{HockeyTeamData, PeriodType};
 use crate::common::types::game::{HockeyData, SportData};
+use crate::common::types::game::{PeriodScore, Status};
 use crate::common::types::Game;
 
+fn get_team_data(
+    team: &Map<String, Value>,
+    shootout: Option<&Map<String, Value>>,
+) -> Result<HockeyTeamData, Error> {
+    Ok(HockeyTeamData {
+        powerplay: get_bool(team, "powerPlay")?,
+        num_skaters: get_u64(team, "numSkaters").unwrap_or(5),
+        shots_on_goal: get_u64(team, "shotsOnGoal").unwrap_or(0),
+        goalie_pulled: get_bool(team, "goaliePulled").unwrap_or(false),
+        shootout_goals: shootout
+            .and_then(|s| get_u64(s, "scores").ok())
+            .unwrap_or(0),
+        shootout_attempts: shootout
+            .and_then(|s| get_u64(s, "attempts").ok())
+            .unwrap_or(0),
+    })
+}
+
 pub async fn fetch_hockey(mut game: Game) -> Result<Game, Error> {
     println!("Fetching extra data for hockey game {:?}", game.game_id);
     let schedule_url = format!(
@@ -23,48 +46,86 @@ pub async fn fetch_hockey(mut game: Game) -> Result<Game, Error> {
     game.home_team_score = home_score;
     game.away_team_score = away_score;
 
-    let away_powerplay = get_bool(away, "powerPlay")?;
-    let home_powerplay = get_bool(home, "powerPlay")?;
-    let away_players = get_u64(away, "numSkaters").unwrap_or(5);
-    let home_players = get_u64(home, "numSkaters").unwrap_or(5);
+    // Playoff games never go to a shootout, they keep playing 20 minute overtimes
+    let is_playoff = game.postseason.is_some();
     let period = get_u64(&json, "currentPeriod")?;
     game.period = period;
-
-    let period_time = get_str(&json, "currentPeriodTimeRemaining").unwrap_or("20:00");
-    if period >= 1 {
+    if is_playoff && period > 4 {
+        game.ordinal = format!("{}OT", period - 3);
+    } else if period >= 1 {
         game.ordinal = get_str(&json, "currentPeriodOrdinal")
             .unwrap_or("1st")
             .to_string();
     }
 
-    let status = if period_time == "Final" {
-        Status::End
-    } else if period_time == "END" {
-        if period >= 3 && away_score != home_score {
-            Status::End
-        } else {
-            game.ordinal += " INT";
-            Status::Intermission
-        }
-    } else if period_time == "20:00" && period > 1 {
+    let in_intermission = get_object(&json, "intermissionInfo")
+        .and_then(|info| get_bool(info, "inIntermission"))
+        .unwrap_or(false);
+    if game.status() == Status::Active && in_intermission {
         game.ordinal += " INT";
-        Status::Intermission
-    } else if period_time == "20:00" && period >= 1 {
-        Status::Active
+        game.set_status(Status::Intermission);
+    }
+
+    let period_type = if !is_playoff
+        && (get_bool(&json, "hasShootout").unwrap_or(false) || game.ordinal == "SO")
+    {
+        PeriodType::Shootout
+    } else if period > 3 {
+        PeriodType::Overtime
     } else {
-        Status::Pregame
+        PeriodType::Regulation
     };
 
-    game.status = status.into();
+    let time_remaining = if game.status() == Status::Active {
+        get_str(&json, "currentPeriodTimeRemaining")
+            .unwrap_or_default()
+            .to_owned()
+    } else {
+        String::new()
+    };
+
+    let powerplay_time_remaining = match get_object(&json, "powerPlayInfo") {
+        Ok(info) if get_bool(info, "inSituation").unwrap_or(false) => {
+            let seconds = get_u64(info, "situationTimeRemaining").unwrap_or(0);
+            format!("{}:{:02}", seconds / 60, seconds % 60)
+        }
+        _ => String::new(),
+    };
+
+    let mut period_scores = vec![];
+    for linescore in get_array(&json, "periods")
+        .map(|p| p.as_slice())
+        .unwrap_or_default()
+    {
+        let goals = |side| {
+            get_object_from_value(linescore, side)
+                .and_then(|team| get_u64(team, "goals"))
+                .unwrap_or(0)
+        };
+        period_scores.push(PeriodScore {
+            period: get_u64_from_value(linescore, "num")?,
+            label: get_str_from_value(linescore, "ordinalNum")
+                .unwrap_or_default()
+                .to_owned(),
+            home_score: goals("home"),
+            away_score: goals("away"),
+        });
+    }
+    game.period_scores = period_scores;
+
+    let shootout = get_object(&json, "shootoutInfo").ok();
     game.sport_data = Some(SportData::HockeyData(HockeyData {
-        home_team: Some(HockeyTeamData {
-            powerplay: home_powerplay,
-            num_skaters: home_players,
-        }),
-        away_team: Some(HockeyTeamData {
-            powerplay: away_powerplay,
-            num_skaters: away_players,
-        }),
+        home_team: Some(get_team_data(
+            home,
+            shootout.and_then(|s| get_object(s, "home").ok()),
+        )?),
+        away_team: Some(get_team_data(
+            away,
+            shootout.and_then(|s| get_object(s, "away").ok()),
+        )?),
+        time_remaining,
+        powerplay_time_remaining,
+        period_type: period_type.into(),
     }));
 
     println!("Got extra data for hockey game {:?}", game.game_id);
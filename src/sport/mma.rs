@@ -0,0 +1,242 @@
+use itertools::Itertools;
+use serde_json::Value;
+
+use crate::common::competition::{get_broadcasts, get_status_and_time, get_venue};
+use crate::common::data::Error;
+use crate::common::types::game::fight_data::{Fighter, Winner};
+use crate::common::types::game::{FightData, SportData, Status};
+use crate::common::types::{Game, Sport};
+
+use crate::common::processors::{
+    get_array_from_value, get_bool_from_value, get_object, get_object_from_value, get_str,
+    get_str_from_value, get_u64, get_u64_str_from_value,
+};
+use crate::common::team::get_last_name;
+
+fn from_fighter(competitor: &Value) -> Result<Fighter, Error> {
+    let athlete = get_object_from_value(competitor, "athlete")?;
+    let name = get_str(athlete, "displayName")?.to_uppercase();
+    let display_name = get_last_name(&name);
+    let country = get_object(athlete, "flag")
+        .and_then(|flag| get_str(flag, "alt"))
+        .unwrap_or_default()
+        .to_owned();
+    let record = get_array_from_value(competitor, "records")
+        .ok()
+        .and_then(|records| records.first())
+        .and_then(|record| get_str_from_value(record, "summary").ok())
+        .unwrap_or_default()
+        .to_owned();
+    Ok(Fighter {
+        name,
+        display_name,
+        record,
+        country,
+    })
+}
+
+fn process_bout(
+    sport: &Sport,
+    promotion: &str,
+    event_name: &str,
+    bout_order: u64,
+    competition: &Value,
+) -> Result<Option<Game>, Error> {
+    let competitors = get_array_from_value(competition, "competitors")?;
+    let status_object = get_object_from_value(competition, "status")?;
+    let (home, away) = competitors
+        .iter()
+        .collect_tuple()
+        .ok_or("Failed to unwrap home fighter and away fighter")?;
+    // skip bouts > 12 hours ago or in the future
+    let (status, time) = match get_status_and_time(competition, Some(12))? {
+        Some(status_and_time) => status_and_time,
+        None => return Ok(None),
+    };
+
+    let round = get_u64(status_object, "period").unwrap_or(0);
+    let scheduled_rounds = get_object_from_value(competition, "format")
+        .and_then(|format| get_object(format, "regulation"))
+        .and_then(|regulation| get_u64(regulation, "periods"))
+        .unwrap_or(3);
+    let round_clock = if status == Status::Active {
+        get_str(status_object, "displayClock").unwrap_or_default()
+    } else {
+        ""
+    }
+    .to_owned();
+
+    let weight_class = get_object_from_value(competition, "type")
+        .and_then(|t| get_str(t, "text"))
+        .unwrap_or_default()
+        .to_uppercase();
+
+    let winner = if get_bool_from_value(home, "winner").unwrap_or(false) {
+        Winner::Home
+    } else if get_bool_from_value(away, "winner").unwrap_or(false) {
+        Winner::Away
+    } else {
+        Winner::None
+    };
+    let method = if status == Status::End {
+        get_object(status_object, "result")
+            .and_then(|result| get_str(result, "shortDisplayName"))
+            .unwrap_or_default()
+    } else {
+        ""
+    }
+    .to_uppercase();
+
+    let ordinal = match status {
+        Status::End if !method.is_empty() => format!("{method} R{round}"),
+        Status::Intermission => format!("END R{round}"),
+        _ => format!("R{round}"),
+    };
+
+    Ok(Some(Game {
+        game_id: get_u64_str_from_value(competition, "id")?,
+        sport: Some(*sport),
+        home_team: None,
+        away_team: None,
+        home_team_score: 0,
+        away_team_score: 0,
+        status: status.into(),
+        period: round,
+        ordinal,
+        start_time: time.timestamp_nanos(),
+        sport_data: Some(SportData::FightData(FightData {
+            event_name: event_name.to_owned(),
+            promotion: promotion.to_owned(),
+            weight_class,
+            home_fighter: Some(from_fighter(home)?),
+            away_fighter: Some(from_fighter(away)?),
+            round,
+            scheduled_rounds,
+            round_clock,
+            method,
+            winner: winner.into(),
+            bout_order,
+        })),
+        broadcasts: get_broadcasts(competition),
+        venue: get_venue(competition),
+        ..Default::default()
+    }))
+}
+
+pub fn process_fights(
+    sport: &Sport,
+    promotion: &str,
+    events: &Vec<Value>,
+) -> Result<Vec<Game>, Error> {
+    let mut out_games = Vec::new();
+
+    for event in events {
+        let event_name = get_str_from_value(event, "shortName")
+            .or_else(|_| get_str_from_value(event, "name"))?
+            .to_uppercase();
+
+        // Each bout on the card is its own competition, listed from the first prelim to the main event
+        let competitions = get_array_from_value(event, "competitions")?;
+        for (bout_order, competition) in competitions.iter().enumerate() {
+            if let Some(game) = process_bout(
+                sport,
+                promotion,
+                &event_name,
+                bout_order as u64,
+                competition,
+            )? {
+                out_games.push(game);
+            }
+        }
+    }
+    Ok(out_games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use crate::common::types::sport::{Level, SportType};
+    use serde_json::json;
+
+    fn fighter(name: &str, record: &str, winner: bool) -> Value {
+        json!({
+            "athlete": {"displayName": name, "flag": {"alt": "USA"}},
+            "records": [{"summary": record}],
+            "winner": winner,
+        })
+    }
+
+    #[test]
+    fn test_process_fights() {
+        let sport = new_sport(SportType::Mma, Level::Professional);
+        let now = chrono::offset::Utc::now()
+            .format("%Y-%m-%dT%H:%MZ")
+            .to_string();
+        let events = vec![json!({
+            "shortName": "UFC 300",
+            "competitions": [
+                {
+                    "id": "401645001",
+                    "date": now,
+                    "type": {"text": "Lightweight"},
+                    "status": {
+                        "period": 2,
+                        "displayClock": "1:23",
+                        "type": {"name": "STATUS_FINAL"},
+                        "result": {"shortDisplayName": "KO/TKO"},
+                    },
+                    "competitors": [
+                        fighter("Justin Gaethje", "25-4-0", false),
+                        fighter("Max Holloway", "26-7-0", true),
+                    ],
+                },
+                {
+                    "id": "401645002",
+                    "date": now,
+                    "type": {"text": "Light Heavyweight"},
+                    "format": {"regulation": {"periods": 5}},
+                    "status": {
+                        "period": 3,
+                        "displayClock": "2:41",
+                        "type": {"name": "STATUS_IN_PROGRESS"},
+                    },
+                    "competitors": [
+                        fighter("Alex Pereira", "9-2-0", false),
+                        fighter("Jamahal Hill", "12-1-0", false),
+                    ],
+                },
+            ],
+        })];
+
+        let games = process_fights(&sport, "UFC", &events).unwrap();
+        assert_eq!(games.len(), 2);
+
+        let finished = &games[0];
+        assert_eq!(finished.ordinal, "KO/TKO R2");
+        let Some(SportData::FightData(data)) = &finished.sport_data else {
+            panic!("Expected fight data");
+        };
+        assert_eq!(data.weight_class, "LIGHTWEIGHT");
+        assert_eq!(data.method, "KO/TKO");
+        assert_eq!(data.winner(), Winner::Away);
+        assert_eq!(data.round_clock, "");
+        assert_eq!(data.scheduled_rounds, 3);
+        assert_eq!(data.bout_order, 0);
+        assert_eq!(data.home_fighter.as_ref().unwrap().record, "25-4-0");
+
+        let live = &games[1];
+        assert_eq!(live.ordinal, "R3");
+        let Some(SportData::FightData(data)) = &live.sport_data else {
+            panic!("Expected fight data");
+        };
+        assert_eq!(data.event_name, "UFC 300");
+        assert_eq!(data.round, 3);
+        assert_eq!(data.scheduled_rounds, 5);
+        assert_eq!(data.round_clock, "2:41");
+        assert_eq!(data.method, "");
+        assert_eq!(data.winner(), Winner::None);
+        assert_eq!(data.bout_order, 1);
+        assert_eq!(data.away_fighter.as_ref().unwrap().display_name, "HILL");
+    }
+}
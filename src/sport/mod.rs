@@ -3,3 +3,6 @@ pub mod basketball;
 pub mod football;
 pub mod golf;
 pub mod hockey;
+pub mod mma;
+pub mod racing;
+pub mod tennis;
@@ -0,0 +1,261 @@
+use serde_json::Value;
+
+use crate::common::competition::{get_broadcasts, get_status_and_time, get_venue};
+use crate::common::data::Error;
+use crate::common::proto_helpers::from_espn;
+use crate::common::types::game::racing_data::{Flag, RacingDriver};
+use crate::common::types::game::{RacingData, SportData, Status};
+use crate::common::types::{Game, Sport};
+
+use crate::common::processors::{
+    get_array_from_value, get_object, get_object_from_value, get_str, get_str_from_value, get_u64,
+    get_u64_from_value, get_u64_str_from_value,
+};
+use crate::common::team::get_last_name;
+
+const NUM_DRIVERS: usize = 5;
+
+fn from_driver(competitor: &Value) -> Result<RacingDriver, Error> {
+    let full_name =
+        get_str(get_object_from_value(competitor, "athlete")?, "displayName")?.to_uppercase();
+    let display_name = get_last_name(&full_name);
+    let car_number = get_object_from_value(competitor, "vehicle")
+        .and_then(|vehicle| get_str(vehicle, "number"))
+        .unwrap_or_default()
+        .to_owned();
+    let position = get_u64_from_value(competitor, "order")?;
+
+    // The leader has no gap, everyone else is behind on time or on laps
+    let gap = get_array_from_value(competitor, "statistics")
+        .ok()
+        .and_then(|stats| {
+            stats.iter().find(|stat| {
+                matches!(
+                    get_str_from_value(stat, "name"),
+                    Ok("behindTime" | "behindLaps")
+                )
+            })
+        })
+        .and_then(|stat| get_str_from_value(stat, "displayValue").ok())
+        .unwrap_or_default()
+        .to_owned();
+
+    Ok(RacingDriver {
+        name: full_name,
+        display_name,
+        car_number,
+        position,
+        gap,
+    })
+}
+
+fn get_flag(status: Status, detail: &str) -> Flag {
+    let detail = detail.to_uppercase();
+    match status {
+        Status::End => Flag::Checkered,
+        _ if detail.contains("RED") => Flag::Red,
+        _ if detail.contains("CAUTION") || detail.contains("YELLOW") => Flag::Yellow,
+        Status::Active => Flag::Green,
+        _ => Flag::None,
+    }
+}
+
+// Race weekends list every session, prefer the one running now, then the most recent
+fn get_current_session(competitions: &[Value]) -> Result<Option<(&Value, Status)>, Error> {
+    let mut current = None;
+    for competition in competitions {
+        let status_object = get_object_from_value(competition, "status")?;
+        let status = from_espn(get_str(get_object(status_object, "type")?, "name")?);
+        match status {
+            Status::Invalid => continue,
+            Status::Active | Status::Intermission => return Ok(Some((competition, status))),
+            Status::End => current = Some((competition, status)),
+            Status::Pregame => {
+                if current.is_none() {
+                    current = Some((competition, status));
+                }
+            }
+        }
+    }
+    Ok(current)
+}
+
+pub fn process_racing(
+    sport: &Sport,
+    series: &str,
+    events: &Vec<Value>,
+) -> Result<Vec<Game>, Error> {
+    let mut out_games = Vec::new();
+
+    for event in events {
+        let competitions = get_array_from_value(event, "competitions")?;
+        let (competition, status) = match get_current_session(competitions)? {
+            Some(session) => session,
+            None => continue,
+        };
+        let status_object = get_object_from_value(competition, "status")?;
+        let game_id = get_u64_str_from_value(competition, "id")?;
+
+        // skip events > 24 hours ago or in the future, unless they're running or finished
+        let window_hours = match status {
+            Status::Active | Status::End => None,
+            _ => Some(24),
+        };
+        let time = match get_status_and_time(competition, window_hours)? {
+            Some((_, time)) => time,
+            None => {
+                tracing::info!(
+                    "Skipping event {} because it is over 24 hours away, status is {:?}",
+                    game_id,
+                    status
+                );
+                continue;
+            }
+        };
+
+        let mut drivers = vec![];
+        for competitor in get_array_from_value(competition, "competitors")? {
+            drivers.push(from_driver(competitor)?);
+        }
+        drivers.sort_by_key(|d| d.position);
+        drivers.truncate(NUM_DRIVERS);
+
+        let session_type = get_object_from_value(competition, "type")
+            .and_then(|t| get_str(t, "abbreviation"))
+            .unwrap_or("Race")
+            .to_uppercase();
+        let laps_completed = get_u64(status_object, "period").unwrap_or(0);
+        let total_laps = get_u64_from_value(competition, "laps").unwrap_or(0);
+        let detail = get_str(get_object(status_object, "type")?, "detail").unwrap_or_default();
+        let ordinal = if total_laps > 0 {
+            format!("LAP {laps_completed}/{total_laps}")
+        } else {
+            session_type.clone()
+        };
+
+        out_games.push(Game {
+            game_id,
+            sport: Some(*sport),
+            home_team: None,
+            away_team: None,
+            home_team_score: 0,
+            away_team_score: 0,
+            status: status.into(),
+            period: laps_completed,
+            ordinal,
+            start_time: time.timestamp_nanos(),
+            sport_data: Some(SportData::RacingData(RacingData {
+                series: series.to_owned(),
+                event_name: get_str_from_value(event, "shortName")?.to_uppercase(),
+                session_type,
+                laps_completed,
+                total_laps,
+                flag: get_flag(status, detail).into(),
+                drivers,
+            })),
+            broadcasts: get_broadcasts(competition),
+            venue: get_venue(competition),
+            ..Default::default()
+        })
+    }
+    Ok(out_games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use crate::common::types::sport::{Level, SportType};
+    use serde_json::json;
+
+    fn driver(name: &str, number: &str, order: u64, behind: Option<(&str, &str)>) -> Value {
+        let statistics = match behind {
+            Some((stat, gap)) => json!([{"name": stat, "displayValue": gap}]),
+            None => json!([]),
+        };
+        json!({
+            "athlete": {"displayName": name},
+            "vehicle": {"number": number},
+            "order": order,
+            "statistics": statistics,
+        })
+    }
+
+    #[test]
+    fn test_process_racing() {
+        let sport = new_sport(SportType::Racing, Level::Professional);
+        let events = vec![json!({
+            "shortName": "Monaco GP",
+            "competitions": [
+                {
+                    "id": "600041001",
+                    "date": "2023-05-26T11:30Z",
+                    "type": {"abbreviation": "FP1"},
+                    "status": {"type": {"name": "STATUS_FINAL", "detail": "Final"}},
+                    "competitors": [],
+                },
+                {
+                    "id": "600041002",
+                    "date": "2023-05-28T13:00Z",
+                    "type": {"abbreviation": "Race"},
+                    "laps": 78,
+                    "status": {
+                        "period": 31,
+                        "type": {"name": "STATUS_IN_PROGRESS", "detail": "Yellow Flag"},
+                    },
+                    "competitors": [
+                        driver("Lewis Hamilton", "44", 4, Some(("behindTime", "+12.402"))),
+                        driver("Max Verstappen", "1", 1, None),
+                        driver("Logan Sargeant", "2", 20, Some(("behindLaps", "+2 Laps"))),
+                        driver("Fernando Alonso", "14", 2, Some(("behindTime", "+3.115"))),
+                        driver("Carlos Sainz Jr.", "55", 5, Some(("behindTime", "+14.990"))),
+                        driver("Esteban Ocon", "31", 3, Some(("behindTime", "+8.726"))),
+                    ],
+                },
+            ],
+        })];
+
+        let games = process_racing(&sport, "F1", &events).unwrap();
+        assert_eq!(games.len(), 1);
+        let game = &games[0];
+        assert_eq!(game.game_id, 600041002);
+        assert_eq!(game.status(), Status::Active);
+        assert_eq!(game.ordinal, "LAP 31/78");
+        let Some(SportData::RacingData(data)) = &game.sport_data else {
+            panic!("Expected racing data");
+        };
+        assert_eq!(data.event_name, "MONACO GP");
+        assert_eq!(data.session_type, "RACE");
+        assert_eq!(data.flag(), Flag::Yellow);
+        let order = data
+            .drivers
+            .iter()
+            .map(|d| {
+                (
+                    d.display_name.as_str(),
+                    d.car_number.as_str(),
+                    d.gap.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![
+                ("VERSTAPPEN", "1", ""),
+                ("ALONSO", "14", "+3.115"),
+                ("OCON", "31", "+8.726"),
+                ("HAMILTON", "44", "+12.402"),
+                ("SAINZ", "55", "+14.990"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_flag() {
+        assert_eq!(get_flag(Status::End, "Red Flag"), Flag::Checkered);
+        assert_eq!(get_flag(Status::Intermission, "Red Flag"), Flag::Red);
+        assert_eq!(get_flag(Status::Active, "Caution"), Flag::Yellow);
+        assert_eq!(get_flag(Status::Active, "Lap 12"), Flag::Green);
+        assert_eq!(get_flag(Status::Pregame, "Sun, May 28"), Flag::None);
+    }
+}
@@ -0,0 +1,290 @@
+use itertools::Itertools;
+use ordinal::Ordinal;
+use serde_json::Value;
+
+use crate::common::competition::{get_broadcasts, get_status_and_time, get_venue};
+use crate::common::data::Error;
+use crate::common::types::game::tennis_data::{Server, SetScore, TennisPlayer};
+use crate::common::types::game::{SportData, Status, TennisData};
+use crate::common::types::{Game, Sport};
+
+use crate::common::processors::{
+    get_array_from_value, get_bool_from_value, get_f64_from_value, get_object,
+    get_object_from_value, get_str, get_str_from_value, get_u64, get_u64_str_from_value,
+};
+use crate::common::team::get_last_name;
+
+fn from_tennis_competitor(competitor: &Value) -> Result<TennisPlayer, Error> {
+    let (name, display_name, country) =
+        if let Ok(athlete) = get_object_from_value(competitor, "athlete") {
+            let full_name = get_str(athlete, "displayName")?.to_uppercase();
+            let last_name = get_last_name(&full_name);
+            let country = get_object(athlete, "flag")
+                .and_then(|flag| get_str(flag, "alt"))
+                .unwrap_or_default()
+                .to_owned();
+            (full_name, last_name, country)
+        } else {
+            // Doubles teams list both partners in the roster
+            let mut names = vec![];
+            let roster = get_array_from_value(competitor, "roster")?;
+            for player in roster {
+                let full_name = get_str(get_object_from_value(player, "athlete")?, "displayName")?
+                    .to_uppercase();
+                names.push(get_last_name(&full_name));
+            }
+            let name = names.iter().join("/");
+            (name.clone(), name, String::new())
+        };
+
+    let seed = get_u64_str_from_value(competitor, "seed").unwrap_or(0);
+    Ok(TennisPlayer {
+        name,
+        display_name,
+        country,
+        seed,
+    })
+}
+
+fn get_linescores(competitor: &Value) -> Vec<(u64, u64, bool)> {
+    get_array_from_value(competitor, "linescores")
+        .map(|linescores| {
+            linescores
+                .iter()
+                .map(|set| {
+                    let games = get_f64_from_value(set, "value").unwrap_or(0.0) as u64;
+                    let tiebreak = get_f64_from_value(set, "tiebreak").unwrap_or(0.0) as u64;
+                    let won = get_bool_from_value(set, "winner").unwrap_or(false);
+                    (games, tiebreak, won)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn process_match(
+    sport: &Sport,
+    event_name: &str,
+    competition: &Value,
+) -> Result<Option<Game>, Error> {
+    let competitors = get_array_from_value(competition, "competitors")?;
+    let status_object = get_object_from_value(competition, "status")?;
+    let (home, away) = competitors
+        .iter()
+        .collect_tuple()
+        .ok_or("Failed to unwrap home player and away player")?;
+    // skip matches > 12 hours ago or in the future
+    let (status, time) = match get_status_and_time(competition, Some(12))? {
+        Some(status_and_time) => status_and_time,
+        None => return Ok(None),
+    };
+
+    let home_sets = get_linescores(home);
+    let away_sets = get_linescores(away);
+    let sets = home_sets
+        .iter()
+        .zip(away_sets.iter())
+        .map(
+            |((home_games, home_tiebreak, _), (away_games, away_tiebreak, _))| SetScore {
+                home_games: *home_games,
+                away_games: *away_games,
+                home_tiebreak: *home_tiebreak,
+                away_tiebreak: *away_tiebreak,
+            },
+        )
+        .collect_vec();
+    let home_sets_won = home_sets.iter().filter(|(_, _, won)| *won).count() as u64;
+    let away_sets_won = away_sets.iter().filter(|(_, _, won)| *won).count() as u64;
+
+    let is_tiebreak = status == Status::Active
+        && sets
+            .last()
+            .is_some_and(|set| set.home_games == set.away_games && set.home_games >= 6);
+
+    let server = if get_bool_from_value(home, "possession").unwrap_or(false) {
+        Server::Home
+    } else if get_bool_from_value(away, "possession").unwrap_or(false) {
+        Server::Away
+    } else {
+        Server::None
+    };
+
+    // ESPN only includes the point score while a match is live
+    let (home_game_score, away_game_score) = if status == Status::Active {
+        (
+            get_str_from_value(home, "currentPoint")
+                .unwrap_or_default()
+                .to_owned(),
+            get_str_from_value(away, "currentPoint")
+                .unwrap_or_default()
+                .to_owned(),
+        )
+    } else {
+        (String::new(), String::new())
+    };
+
+    let period = get_u64(status_object, "period").unwrap_or(sets.len() as u64);
+    let ordinal = format!("{} SET", Ordinal(period));
+    let round_name = get_object_from_value(competition, "round")
+        .and_then(|round| get_str(round, "displayName"))
+        .unwrap_or_default()
+        .to_uppercase();
+
+    Ok(Some(Game {
+        game_id: get_u64_str_from_value(competition, "id")?,
+        sport: Some(*sport),
+        home_team: None,
+        away_team: None,
+        home_team_score: home_sets_won,
+        away_team_score: away_sets_won,
+        status: status.into(),
+        period,
+        ordinal,
+        start_time: time.timestamp_nanos(),
+        sport_data: Some(SportData::TennisData(TennisData {
+            event_name: event_name.to_owned(),
+            round_name,
+            home_player: Some(from_tennis_competitor(home)?),
+            away_player: Some(from_tennis_competitor(away)?),
+            sets,
+            home_game_score,
+            away_game_score,
+            server: server.into(),
+            is_tiebreak,
+        })),
+        broadcasts: get_broadcasts(competition),
+        venue: get_venue(competition),
+        ..Default::default()
+    }))
+}
+
+pub fn process_tennis(sport: &Sport, events: &Vec<Value>) -> Result<Vec<Game>, Error> {
+    let mut out_games = Vec::new();
+
+    for event in events {
+        let event_name = get_str_from_value(event, "shortName")
+            .or_else(|_| get_str_from_value(event, "name"))?
+            .to_uppercase();
+
+        // Tournaments group their matches by draw (singles, doubles, ...)
+        let competitions = match get_array_from_value(event, "groupings") {
+            Ok(groupings) => {
+                let mut competitions = vec![];
+                for grouping in groupings {
+                    competitions.extend(get_array_from_value(grouping, "competitions")?);
+                }
+                competitions
+            }
+            Err(_) => get_array_from_value(event, "competitions")?
+                .iter()
+                .collect(),
+        };
+
+        for competition in competitions {
+            if let Some(game) = process_match(sport, &event_name, competition)? {
+                out_games.push(game);
+            }
+        }
+    }
+    Ok(out_games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::proto_helpers::new_sport;
+    use crate::common::types::sport::{Level, SportType};
+    use serde_json::json;
+
+    #[test]
+    fn test_process_tennis() {
+        let sport = new_sport(SportType::Tennis, Level::Professional);
+        let now = chrono::offset::Utc::now()
+            .format("%Y-%m-%dT%H:%MZ")
+            .to_string();
+        let events = vec![json!({
+            "shortName": "Wimbledon",
+            "groupings": [{
+                "competitions": [{
+                    "id": "158012",
+                    "date": now,
+                    "round": {"displayName": "Final"},
+                    "status": {"period": 2, "type": {"name": "STATUS_IN_PROGRESS"}},
+                    "competitors": [
+                        {
+                            "athlete": {"displayName": "Carlos Alcaraz", "flag": {"alt": "Spain"}},
+                            "seed": "3",
+                            "possession": true,
+                            "currentPoint": "40",
+                            "linescores": [
+                                {"value": 7.0, "tiebreak": 7.0, "winner": true},
+                                {"value": 6.0},
+                            ],
+                        },
+                        {
+                            "athlete": {"displayName": "Novak Djokovic", "flag": {"alt": "Serbia"}},
+                            "seed": "2",
+                            "possession": false,
+                            "currentPoint": "15",
+                            "linescores": [
+                                {"value": 6.0, "tiebreak": 5.0, "winner": false},
+                                {"value": 6.0},
+                            ],
+                        },
+                    ],
+                }],
+            }],
+        })];
+
+        let games = process_tennis(&sport, &events).unwrap();
+        assert_eq!(games.len(), 1);
+        let game = &games[0];
+        assert_eq!((game.home_team_score, game.away_team_score), (1, 0));
+        assert_eq!(game.ordinal, "2nd SET");
+        let Some(SportData::TennisData(data)) = &game.sport_data else {
+            panic!("Expected tennis data");
+        };
+        assert_eq!(data.event_name, "WIMBLEDON");
+        assert_eq!(data.round_name, "FINAL");
+        assert_eq!(
+            data.sets,
+            vec![
+                SetScore {
+                    home_games: 7,
+                    away_games: 6,
+                    home_tiebreak: 7,
+                    away_tiebreak: 5,
+                },
+                SetScore {
+                    home_games: 6,
+                    away_games: 6,
+                    home_tiebreak: 0,
+                    away_tiebreak: 0,
+                },
+            ]
+        );
+        assert!(data.is_tiebreak);
+        assert_eq!(data.server(), Server::Home);
+        assert_eq!(
+            (data.home_game_score.as_str(), data.away_game_score.as_str()),
+            ("40", "15")
+        );
+        let home_player = data.home_player.as_ref().unwrap();
+        assert_eq!(home_player.display_name, "ALCARAZ");
+        assert_eq!(home_player.country, "Spain");
+        assert_eq!(home_player.seed, 3);
+    }
+
+    #[test]
+    fn test_from_tennis_competitor_doubles() {
+        let competitor = json!({
+            "roster": [
+                {"athlete": {"displayName": "Rajeev Ram"}},
+                {"athlete": {"displayName": "Joe Salisbury"}},
+            ],
+        });
+        let team = from_tennis_competitor(&competitor).unwrap();
+        assert_eq!(team.display_name, "RAM/SALISBURY");
+        assert_eq!(team.seed, 0);
+    }
+}